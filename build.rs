@@ -1,6 +1,38 @@
+use std::{env, fs, path::Path};
+
 fn main() {
-    let target = std::env::var("TARGET").unwrap();
+    let target = env::var("TARGET").unwrap();
     if target.contains("windows") {
+        println!("cargo:rerun-if-changed=icon.ico");
+        println!("cargo:rerun-if-changed=icon.rc");
         embed_resource::compile("icon.rc");
     }
+
+    generate_level_list();
+}
+
+/// Generates the list of levels that are built into the game from the level
+/// files in `assets/levels`, so it keeps matching the files that are added,
+/// deleted and renamed by the editor.
+fn generate_level_list() {
+    println!("cargo:rerun-if-changed=assets/levels");
+
+    let mut levels = String::new();
+    for level_number in 1.. {
+        let filename = format!("assets/levels/level{level_number:0>3}");
+        if !Path::new(&filename).exists() {
+            break;
+        }
+
+        levels.push_str(&format!(
+            "    include_str!(concat!(env!(\"CARGO_MANIFEST_DIR\"), \"/{filename}\")),\n"
+        ));
+    }
+
+    let out_dir = env::var("OUT_DIR").unwrap();
+    fs::write(
+        Path::new(&out_dir).join("levels.rs"),
+        format!("&[\n{levels}]\n"),
+    )
+    .unwrap();
 }
@@ -1,8 +1,8 @@
 use bevy::prelude::*;
 
 use crate::{
-    constants::*, editor::Editor, level::Dimensions, utils::load_repeating_asset, Player, Position,
-    TransformEvent, Zoom,
+    constants::*, editor::Editor, level::Dimensions, utils::load_repeating_asset, ActivePlayer,
+    Position, TransformEvent, Zoom,
 };
 
 const BACKGROUND_ASSET: &[u8] = include_bytes!("../assets/sprites/background.png");
@@ -58,7 +58,7 @@ pub fn update_background_transform(
     mut events: EventReader<TransformEvent>,
     mut background_query: Query<&mut Transform, With<Background>>,
     editor_query: Query<Entity, With<Editor>>,
-    player_query: Query<&Position, With<Player>>,
+    player_query: Query<&Position, With<ActivePlayer>>,
     window_query: Query<&Window>,
     dimensions: Res<Dimensions>,
     zoom: Res<Zoom>,
@@ -0,0 +1,263 @@
+use std::collections::BTreeMap;
+
+use bevy::{ecs::query::QueryItem, prelude::*};
+
+use crate::{
+    background::Background,
+    collect_level_objects,
+    game_object::{
+        Age, Channel, Checkpoint, Detonating, Direction, GameObjectAssets, Massive, ObjectType,
+        Openable, Player, Position, Receiver, Rolling, TransformOnLeave,
+    },
+    hud::CollectedItems,
+    level::{Dimensions, InitialPositionAndDirection},
+    spawn_level_objects, LevelObject, PressedTriggers,
+};
+
+#[derive(Event)]
+pub enum CheckpointEvent {
+    Respawn,
+}
+
+/// State of an object that changes while playing, on top of what is saved in
+/// the level.
+#[derive(Clone)]
+struct ObjectState {
+    object_type: ObjectType,
+    position: Position,
+    is_open: bool,
+    rolling_direction: Option<Direction>,
+    age: Option<u16>,
+    is_detonating: bool,
+    is_activated: bool,
+    is_occupied: bool,
+}
+
+impl ObjectState {
+    /// Returns the state of the given object, or `None` if it is still in its
+    /// initial state.
+    fn new(
+        (
+            object_type,
+            position,
+            is_openable,
+            is_massive,
+            rolling,
+            age,
+            is_detonating,
+            receiver,
+            transform_on_leave,
+        ): QueryItem<CheckpointSystemObject>,
+    ) -> Option<Self> {
+        let state = Self {
+            object_type: *object_type,
+            position: *position,
+            is_open: is_openable && !is_massive,
+            rolling_direction: rolling.and_then(|rolling| rolling.direction),
+            age: age.map(|age| age.0),
+            is_detonating,
+            is_activated: receiver.is_some_and(|receiver| receiver.activated),
+            is_occupied: transform_on_leave.is_some_and(|transform| transform.occupied),
+        };
+
+        (state.is_open
+            || state.rolling_direction.is_some()
+            || state.age.is_some()
+            || state.is_detonating
+            || state.is_activated
+            || state.is_occupied)
+            .then_some(state)
+    }
+}
+
+type CheckpointSystemObject<'a> = (
+    &'a ObjectType,
+    &'a Position,
+    Has<Openable>,
+    Has<Massive>,
+    Option<&'a Rolling>,
+    Option<&'a Age>,
+    Has<Detonating>,
+    Option<&'a Receiver>,
+    Option<&'a TransformOnLeave>,
+);
+
+/// Snapshot of the board, taken when a player steps onto a [Checkpoint].
+#[derive(Default, Resource)]
+pub struct CheckpointSnapshot {
+    objects: Option<BTreeMap<ObjectType, Vec<InitialPositionAndDirection>>>,
+
+    /// States of the objects that are not in their initial state.
+    states: Vec<ObjectState>,
+
+    /// States that still need to be restored on the respawned objects.
+    pending_states: Vec<ObjectState>,
+
+    /// Number of pressed triggers on each channel at the checkpoint, so
+    /// respawning does not press them again.
+    num_pressed_triggers: BTreeMap<Channel, usize>,
+
+    /// Position of the checkpoint the snapshot was taken at.
+    position: Option<Position>,
+
+    /// Number of collectibles that had been collected at the checkpoint.
+    num_collected: usize,
+}
+
+impl CheckpointSnapshot {
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    pub fn is_available(&self) -> bool {
+        self.objects.is_some()
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn check_for_checkpoints(
+    mut checkpoint_query: Query<(&Position, &mut TextureAtlas, Ref<Checkpoint>)>,
+    mut snapshot: ResMut<CheckpointSnapshot>,
+    player_query: Query<Ref<Position>, With<Player>>,
+    objects_query: Query<LevelObject>,
+    state_query: Query<CheckpointSystemObject>,
+    collected_items: Res<CollectedItems>,
+    pressed_triggers: Res<PressedTriggers>,
+    dimensions: Res<Dimensions>,
+) {
+    // Checkpoints are respawned along with everything else, so restore which
+    // one is active.
+    for (position, mut atlas, checkpoint) in &mut checkpoint_query {
+        if checkpoint.is_added() && snapshot.position == Some(*position) {
+            atlas.index = 1;
+        }
+    }
+
+    let Some(checkpoint_position) = checkpoint_query.iter().find_map(|(position, ..)| {
+        player_query
+            .iter()
+            .any(|player_position| {
+                player_position.is_changed()
+                    && !player_position.is_added()
+                    && *player_position == *position
+            })
+            .then_some(*position)
+    }) else {
+        return;
+    };
+
+    snapshot.objects = Some(collect_level_objects(objects_query.iter(), &dimensions));
+    snapshot.states = state_query.iter().filter_map(ObjectState::new).collect();
+    snapshot.position = Some(checkpoint_position);
+    snapshot.num_collected = collected_items.num_collected;
+    snapshot
+        .num_pressed_triggers
+        .clone_from(&pressed_triggers.num_pressed_triggers);
+
+    for (position, mut atlas, _) in &mut checkpoint_query {
+        atlas.index = if *position == checkpoint_position {
+            1
+        } else {
+            0
+        };
+    }
+}
+
+pub fn respawn_at_checkpoint(
+    mut commands: Commands,
+    mut events: EventReader<CheckpointEvent>,
+    mut collected_items: ResMut<CollectedItems>,
+    mut pressed_triggers: ResMut<PressedTriggers>,
+    mut snapshot: ResMut<CheckpointSnapshot>,
+    background_query: Query<Entity, With<Background>>,
+    assets: Res<GameObjectAssets>,
+) {
+    let Some(_event) = events.read().last() else {
+        return;
+    };
+
+    let Some(objects) = snapshot.objects.clone() else {
+        return;
+    };
+
+    let background_entity = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let mut background = commands.entity(background_entity);
+    background.despawn_descendants();
+    background.with_children(|cb| {
+        spawn_level_objects(cb, objects, &assets);
+    });
+
+    collected_items.num_collected = snapshot.num_collected;
+    pressed_triggers
+        .num_pressed_triggers
+        .clone_from(&snapshot.num_pressed_triggers);
+    pressed_triggers
+        .num_previously_pressed_triggers
+        .clone_from(&snapshot.num_pressed_triggers);
+    snapshot.pending_states = snapshot.states.clone();
+}
+
+/// Restores the states the objects had at the checkpoint, once they have been
+/// respawned.
+#[allow(clippy::type_complexity)]
+pub fn restore_object_states(
+    mut commands: Commands,
+    mut snapshot: ResMut<CheckpointSnapshot>,
+    mut query: Query<
+        (
+            Entity,
+            &ObjectType,
+            &Position,
+            Option<&mut TextureAtlas>,
+            Option<&mut Rolling>,
+            Option<&mut Receiver>,
+            Option<&mut TransformOnLeave>,
+        ),
+        Added<ObjectType>,
+    >,
+) {
+    if snapshot.pending_states.is_empty() {
+        return;
+    }
+
+    for (entity, object_type, position, atlas, rolling, receiver, transform_on_leave) in &mut query
+    {
+        let Some(index) = snapshot
+            .pending_states
+            .iter()
+            .position(|state| state.object_type == *object_type && state.position == *position)
+        else {
+            continue;
+        };
+        let state = snapshot.pending_states.swap_remove(index);
+
+        let mut object = commands.entity(entity);
+        if state.is_open {
+            object.remove::<Massive>();
+            if let Some(mut atlas) = atlas {
+                atlas.index = 1;
+            }
+        }
+        if let Some(mut rolling) = rolling {
+            rolling.direction = state.rolling_direction;
+        }
+        if let Some(age) = state.age {
+            object.insert(Age(age));
+        }
+        if state.is_detonating {
+            object.insert(Detonating);
+        }
+        if let Some(mut receiver) = receiver {
+            receiver.activated = state.is_activated;
+        }
+        if let Some(mut transform_on_leave) = transform_on_leave {
+            transform_on_leave.occupied = state.is_occupied;
+        }
+    }
+
+    // States of objects that were not respawned are not kept around.
+    snapshot.pending_states.clear();
+}
@@ -9,7 +9,10 @@ pub const HALF_GRID_SIZE: i16 = GRID_SIZE / 2;
 pub const BLUE: Color = Color::srgb(0., 0.443, 0.945);
 pub const DARK_GRAY: Color = Color::srgb(0.011764706, 0.02745098, 0.07058824);
 pub const GRAY_BACKGROUND: Color = Color::srgb(0.06666667, 0.09411765, 0.15294118);
+pub const ISSUE_RED: Color = Color::srgba(0.776, 0., 0., 0.4);
 pub const LIGHT_GRAY: Color = Color::srgb(0.29411766, 0.33333334, 0.3882353);
 pub const NORMAL_GRAY: Color = Color::srgb(0.12156863, 0.16078432, 0.21568628);
 pub const RED: Color = Color::srgb(0.776, 0., 0.);
+pub const SELECTION_BLUE: Color = Color::srgba(0., 0.443, 0.945, 0.3);
+pub const TRANSLUCENT_WHITE: Color = Color::srgba(1., 1., 1., 0.6);
 pub const WHITE: Color = Color::srgb(1., 1., 1.);
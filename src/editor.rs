@@ -1,15 +1,42 @@
 mod button;
 mod editor_bundle;
 mod editor_system;
+mod history;
+mod inspector;
+mod inspector_system;
+mod layer_selector_bundle;
+mod layer_selector_system;
 mod number_input;
 mod object_selector_bundle;
 mod object_selector_system;
+mod panel;
+mod resize_dialog;
+mod resize_dialog_system;
+mod selection;
+mod selection_system;
+mod tool_selector_bundle;
+mod tool_selector_system;
+mod validation;
+mod validation_system;
 
 use bevy::prelude::*;
 pub use editor_bundle::*;
 pub use editor_system::*;
+pub use history::*;
+pub use inspector::*;
+use inspector_system::*;
+pub use layer_selector_bundle::*;
+use layer_selector_system::*;
 pub use object_selector_bundle::*;
 use object_selector_system::*;
+pub use resize_dialog::*;
+use resize_dialog_system::*;
+pub use selection::*;
+use selection_system::*;
+pub use tool_selector_bundle::*;
+use tool_selector_system::*;
+pub use validation::*;
+use validation_system::*;
 
 pub struct EditorPlugin;
 
@@ -20,12 +47,48 @@ impl Plugin for EditorPlugin {
             (
                 on_dimensions_changed,
                 on_editor_button_interaction,
+                on_editor_keyboard_input,
                 on_editor_number_input_interaction,
+                on_inspector_input,
+                on_issue_interaction,
+                on_layer_toggle_input,
+                on_levels_changed,
                 on_object_selector_input,
+                on_object_selector_keyboard_input,
+                on_playtest_click,
+                on_resize_dialog_input,
                 on_selected_object_change,
+                on_selected_tool_change,
+                on_selection_keyboard_input,
+                on_tool_selector_input,
+                on_visible_layers_change,
+            ),
+        )
+        .add_systems(
+            Update,
+            (
+                add_waypoint.before(select_area),
+                rotate_selected_object,
+                select_area,
                 spawn_selected_object,
+                update_inspector.after(select_area),
+                update_issue_highlight.after(validate_level),
+                update_layer_visibility,
+                update_placement_preview,
+                update_resize_dialog.after(on_resize_dialog_input),
+                update_selection_highlight.after(select_area),
+                update_validation_panel.after(validate_level),
+                validate_level,
             ),
         )
-        .init_resource::<SelectedObjectType>();
+        .init_resource::<Clipboard>()
+        .init_resource::<EditorHistory>()
+        .init_resource::<LevelValidation>()
+        .init_resource::<ResizeDialog>()
+        .init_resource::<SelectedObjectType>()
+        .init_resource::<SelectedTool>()
+        .init_resource::<Selection>()
+        .init_resource::<VisibleLayers>()
+        .init_resource::<WaypointEditing>();
     }
 }
@@ -7,6 +7,13 @@ use crate::{
 
 #[derive(Clone, Component, Copy)]
 pub enum Button {
+    DeleteLevel,
+    DuplicateLevel,
+    MoveLevelBackward,
+    MoveLevelForward,
+    NewLevel,
+    Play,
+    Resize,
     Save,
 }
 
@@ -18,6 +25,10 @@ pub struct EditorButtonBundle {
 impl EditorButtonBundle {
     #[allow(clippy::new_ret_no_self)]
     pub fn new(marker: impl Bundle) -> impl Bundle {
+        Self::with_width(marker, 100.)
+    }
+
+    pub fn with_width(marker: impl Bundle, width: f32) -> impl Bundle {
         (
             marker,
             Self {
@@ -26,7 +37,7 @@ impl EditorButtonBundle {
                     border_radius: BorderRadius::all(Val::Px(4.)),
                     style: Style {
                         height: Val::Px(30.),
-                        width: Val::Px(100.),
+                        width: Val::Px(width),
                         align_content: AlignContent::Center,
                         ..Default::default()
                     },
@@ -5,14 +5,19 @@ use crate::{constants::*, fonts::Fonts, game_object::GameObjectAssets, level::Di
 use super::{
     button::{Button, EditorButtonBundle},
     number_input::NumberInputBundle,
-    ObjectSelectorBundle,
+    EditorTool, LayerSelectorBundle, ObjectSelectorBundle, ToolSelectorBundle, VisibleLayers,
 };
 
 const BORDER_WIDTH: f32 = 2.;
+const ROW_GAP: f32 = 12.;
 
 #[derive(Component)]
 pub struct Editor;
 
+/// Text showing the number of the current level.
+#[derive(Component)]
+pub struct LevelNumber;
+
 #[derive(Clone, Component, Copy)]
 pub enum Input {
     Width,
@@ -39,7 +44,7 @@ impl EditorBundle {
                     justify_content: JustifyContent::Start,
                     right: Val::Px(0.),
                     position_type: PositionType::Absolute,
-                    row_gap: Val::Px(EDITOR_PADDING as f32),
+                    row_gap: Val::Px(ROW_GAP),
                     ..Default::default()
                 },
                 background_color: GRAY_BACKGROUND.into(),
@@ -55,20 +60,92 @@ impl EditorBundle {
         cb: &mut ChildBuilder,
         assets: &GameObjectAssets,
         dimensions: &Dimensions,
+        selected_tool: EditorTool,
+        visible_layers: &VisibleLayers,
         fonts: &Fonts,
     ) {
         cb.spawn(NumberInputBundle::new()).with_children(|cb| {
-            NumberInputBundle::populate(cb, Input::Width, "Width:", dimensions.width, fonts)
+            NumberInputBundle::populate(cb, Input::Width, "Width:", dimensions.width, fonts);
+
+            cb.spawn(EditorButtonBundle::with_width(Button::Resize, 64.))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Resize", fonts));
         });
 
         cb.spawn(NumberInputBundle::new()).with_children(|cb| {
             NumberInputBundle::populate(cb, Input::Height, "Height:", dimensions.height, fonts)
         });
 
+        cb.spawn(ToolSelectorBundle::new())
+            .with_children(|cb| ToolSelectorBundle::populate(cb, selected_tool, fonts));
+
+        cb.spawn(LayerSelectorBundle::new())
+            .with_children(|cb| LayerSelectorBundle::populate(cb, visible_layers, fonts));
+
         cb.spawn(ObjectSelectorBundle::new())
             .with_children(|cb| ObjectSelectorBundle::populate(cb, assets));
 
-        cb.spawn(EditorButtonBundle::new(Button::Save))
-            .with_children(|cb| EditorButtonBundle::populate(cb, "Save", fonts));
+        cb.spawn(button_row()).with_children(|cb| {
+            cb.spawn(EditorButtonBundle::new(Button::Save))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Save", fonts));
+
+            cb.spawn(EditorButtonBundle::new(Button::Play))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Play", fonts));
+        });
+
+        cb.spawn(button_row()).with_children(|cb| {
+            cb.spawn((
+                LevelNumber,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: fonts.poppins_light.clone(),
+                        font_size: 18.,
+                        color: WHITE,
+                    },
+                )
+                .with_style(Style {
+                    flex_grow: 1.,
+                    ..Default::default()
+                }),
+            ));
+
+            cb.spawn(EditorButtonBundle::with_width(
+                Button::MoveLevelBackward,
+                40.,
+            ))
+            .with_children(|cb| EditorButtonBundle::populate(cb, "<", fonts));
+
+            cb.spawn(EditorButtonBundle::with_width(
+                Button::MoveLevelForward,
+                40.,
+            ))
+            .with_children(|cb| EditorButtonBundle::populate(cb, ">", fonts));
+        });
+
+        cb.spawn(button_row()).with_children(|cb| {
+            cb.spawn(EditorButtonBundle::with_width(Button::NewLevel, 64.))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "New", fonts));
+
+            cb.spawn(EditorButtonBundle::with_width(Button::DuplicateLevel, 64.))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Copy", fonts));
+
+            cb.spawn(EditorButtonBundle::with_width(Button::DeleteLevel, 64.))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Delete", fonts));
+        });
+    }
+}
+
+fn button_row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            width: Val::Percent(100.),
+            height: Val::Px(30.),
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            justify_content: JustifyContent::SpaceBetween,
+            column_gap: Val::Px(10.),
+            ..Default::default()
+        },
+        ..Default::default()
     }
 }
@@ -1,28 +1,65 @@
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
 use bevy::{prelude::*, window::PrimaryWindow};
 
 use crate::{
     constants::*,
-    game_object::{spawn_object_of_type, GameObjectAssets, Position},
-    level::Dimensions,
-    Background, GameEvent, SaveLevelEvent,
+    game_object::{
+        spawn_object_of_type, Channel, Direction, GameObjectAssets, ObjectType, Position, Waypoints,
+    },
+    level::{Dimensions, InitialPositionAndDirection},
+    level_management::LevelEvent,
+    playtest::PlaytestEvent,
+    Background, LevelObject, Levels, SaveLevelEvent,
 };
 
-use super::{button::Button, number_input::NumberInput, Input, SelectedObjectType};
+use super::{
+    button::Button, number_input::NumberInput, resize_level, to_editor_object, Editor,
+    EditorChange, EditorHistory, EditorObjectType, EditorTool, Input, LevelNumber, ResizeDialog,
+    ResizeSettings, SelectedObjectType, SelectedTool, VisibleLayers,
+};
 
 pub fn on_editor_button_interaction(
     mut interaction_query: Query<
         (&Interaction, &Button, &mut BackgroundColor),
         Changed<Interaction>,
     >,
-    mut events: EventWriter<SaveLevelEvent>,
+    mut level_events: EventWriter<LevelEvent>,
+    mut playtest_events: EventWriter<PlaytestEvent>,
+    mut save_events: EventWriter<SaveLevelEvent>,
+    mut resize_dialog: ResMut<ResizeDialog>,
+    dimensions: Res<Dimensions>,
 ) {
     for (interaction, button, mut color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = WHITE.into();
-                events.send(match button {
-                    Button::Save => SaveLevelEvent::Save,
-                });
+                match button {
+                    Button::DeleteLevel => {
+                        level_events.send(LevelEvent::Delete);
+                    }
+                    Button::DuplicateLevel => {
+                        level_events.send(LevelEvent::Duplicate);
+                    }
+                    Button::MoveLevelBackward => {
+                        level_events.send(LevelEvent::MoveBackward);
+                    }
+                    Button::MoveLevelForward => {
+                        level_events.send(LevelEvent::MoveForward);
+                    }
+                    Button::NewLevel => {
+                        level_events.send(LevelEvent::New);
+                    }
+                    Button::Play => {
+                        playtest_events.send(PlaytestEvent::Start(None));
+                    }
+                    Button::Resize => {
+                        resize_dialog.0 = Some(ResizeSettings::new(*dimensions));
+                    }
+                    Button::Save => {
+                        save_events.send(SaveLevelEvent::Save);
+                    }
+                }
             }
             Interaction::Hovered => {
                 *color = LIGHT_GRAY.into();
@@ -34,26 +71,67 @@ pub fn on_editor_button_interaction(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn on_editor_number_input_interaction(
+    mut commands: Commands,
     mut interaction_query: Query<
         (&Interaction, &Input, &NumberInput, &mut BackgroundColor),
         Changed<Interaction>,
     >,
-    mut events: EventWriter<GameEvent>,
+    mut dimensions: ResMut<Dimensions>,
+    mut history: ResMut<EditorHistory>,
+    mut resize_dialog: ResMut<ResizeDialog>,
+    background_query: Query<Entity, With<Background>>,
+    objects: Query<(Entity, LevelObject)>,
+    assets: Res<GameObjectAssets>,
 ) {
     for (interaction, input, number_input, mut color) in &mut interaction_query {
         match *interaction {
             Interaction::Pressed => {
                 *color = WHITE.into();
 
-                let event = match (input, number_input) {
-                    (Input::Width, NumberInput::Increase) => GameEvent::ChangeWidth(1),
-                    (Input::Width, NumberInput::Decrease) => GameEvent::ChangeWidth(-1),
-                    (Input::Height, NumberInput::Increase) => GameEvent::ChangeHeight(1),
-                    (Input::Height, NumberInput::Decrease) => GameEvent::ChangeHeight(-1),
+                let (dx, dy) = match (input, number_input) {
+                    (Input::Width, NumberInput::Increase) => (1, 0),
+                    (Input::Width, NumberInput::Decrease) => (-1, 0),
+                    (Input::Height, NumberInput::Increase) => (0, 1),
+                    (Input::Height, NumberInput::Decrease) => (0, -1),
                     _ => continue,
                 };
-                events.send(event);
+
+                let mut settings = resize_dialog
+                    .0
+                    .unwrap_or_else(|| ResizeSettings::new(*dimensions));
+                if settings.dimensions.width + dx < 1 || settings.dimensions.height + dy < 1 {
+                    continue;
+                }
+                settings.dimensions.width += dx;
+                settings.dimensions.height += dy;
+
+                // The level is resized from its top-left corner right away,
+                // unless that crops objects, in which case the resize dialog
+                // is opened to warn about them first.
+                let crops_objects = objects.iter().any(|(_, object)| {
+                    settings
+                        .resize_object(to_editor_object(object), &dimensions)
+                        .is_none()
+                });
+                if resize_dialog.0.is_some() || crops_objects {
+                    resize_dialog.0 = Some(settings);
+                } else {
+                    let background = background_query
+                        .get_single()
+                        .expect("there should be only one background");
+
+                    resize_level(
+                        &mut commands,
+                        &settings,
+                        &mut dimensions,
+                        &mut history,
+                        background,
+                        &objects,
+                        &assets,
+                    );
+                }
             }
             Interaction::Hovered => {
                 *color = LIGHT_GRAY.into();
@@ -86,38 +164,212 @@ pub fn on_dimensions_changed(
     }
 }
 
+type EditorObjectQuery<'a> = (
+    Entity,
+    &'a Position,
+    Option<&'a ObjectType>,
+    Option<&'a Direction>,
+    Option<&'a Waypoints>,
+    Option<&'a Channel>,
+);
+
+/// Placement that is in progress while the mouse button is held down.
+pub struct Stroke {
+    /// Button the stroke was started with. Strokes with the right button
+    /// erase instead of placing the selected object.
+    button: MouseButton,
+
+    start: Position,
+    end: Position,
+
+    /// Positions the selected object was already placed on during the stroke.
+    placed: BTreeSet<Position>,
+
+    /// All changes made during the stroke, so they are undone at once.
+    change: EditorChange,
+}
+
 #[allow(clippy::too_many_arguments)]
 pub fn spawn_selected_object(
     mut commands: Commands,
+    mut history: ResMut<EditorHistory>,
+    mut stroke: Local<Option<Stroke>>,
     background_query: Query<(Entity, &Transform), With<Background>>,
-    objects: Query<(Entity, &Position)>,
+    editor_query: Query<Entity, With<Editor>>,
+    objects: Query<EditorObjectQuery>,
     selected_object_type: Res<SelectedObjectType>,
+    selected_tool: Res<SelectedTool>,
+    visible_layers: Res<VisibleLayers>,
     buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_query: Query<&Interaction>,
     window_query: Query<&Window, With<PrimaryWindow>>,
     dimensions: Res<Dimensions>,
     assets: Res<GameObjectAssets>,
 ) {
-    if !buttons.just_pressed(MouseButton::Left) {
+    if editor_query.get_single().is_err() || **selected_tool == EditorTool::Select {
+        *stroke = None;
         return;
     }
 
-    let Some(selected_object_type) = **selected_object_type else {
-        return;
-    };
-
     let window = window_query
         .get_single()
         .expect("there should be only one window");
-    let Some(cursor_position) = window.cursor_position() else {
+
+    let (background, transform) = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let position = get_cursor_grid_position(window, transform, &dimensions);
+
+    let pressed_button = if buttons.just_pressed(MouseButton::Left) {
+        Some(MouseButton::Left)
+    } else if buttons.just_pressed(MouseButton::Right) {
+        Some(MouseButton::Right)
+    } else {
+        None
+    };
+    if let Some(button) =
+        pressed_button.filter(|_| !is_alt_pressed(&keys) && !is_cursor_over_ui(&ui_query))
+    {
+        *stroke = position.map(|position| Stroke {
+            button,
+            start: position,
+            end: position,
+            placed: BTreeSet::new(),
+            change: EditorChange::default(),
+        });
+    }
+
+    let Some(current_stroke) = stroke.as_mut() else {
         return;
     };
+    let selected_object_type = match (current_stroke.button, **selected_object_type) {
+        (MouseButton::Right, _) => EditorObjectType::Eraser,
+        (_, Some(selected_object_type)) if visible_layers.can_place(selected_object_type) => {
+            selected_object_type
+        }
+        _ => {
+            *stroke = None;
+            return;
+        }
+    };
+    if let Some(position) = position {
+        current_stroke.end = position;
+    }
 
-    let window_size = window.size();
+    let released = !buttons.pressed(current_stroke.button);
+    // Placed objects only replace objects on their own layer, which is visible
+    // or they would not be placed at all, while the eraser removes everything
+    // on the visible layers.
+    let new_object_type = selected_object_type
+        .get_object_type_and_direction()
+        .map(|(object_type, _)| object_type);
+    let replaces = |object_type: Option<&ObjectType>| match new_object_type {
+        Some(new_object_type) => {
+            object_type.is_none_or(|object_type| object_type.layer() == new_object_type.layer())
+        }
+        None => visible_layers.is_editable(object_type),
+    };
 
-    let (background, transform) = background_query
+    let positions = match **selected_tool {
+        EditorTool::Paint => vec![current_stroke.end],
+        EditorTool::Line if released => line_positions(current_stroke.start, current_stroke.end),
+        EditorTool::Rectangle if released => {
+            rectangle_positions(current_stroke.start, current_stroke.end)
+        }
+        EditorTool::Fill if buttons.just_pressed(current_stroke.button) => {
+            flood_fill_positions(current_stroke.start, &objects, &replaces, &dimensions)
+        }
+        _ => Vec::new(),
+    };
+
+    for position in positions {
+        if current_stroke.placed.insert(position) {
+            place_object(
+                &mut commands,
+                background,
+                &objects,
+                position,
+                selected_object_type,
+                &replaces,
+                &dimensions,
+                &assets,
+                &mut current_stroke.change,
+            );
+        }
+    }
+
+    if released {
+        if let Some(stroke) = stroke.take() {
+            history.push(stroke.change);
+        }
+    }
+}
+
+/// Starts a playtest with the player on the square that is clicked while
+/// holding Alt.
+#[allow(clippy::too_many_arguments)]
+pub fn on_playtest_click(
+    mut events: EventWriter<PlaytestEvent>,
+    background_query: Query<&Transform, With<Background>>,
+    editor_query: Query<Entity, With<Editor>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_query: Query<&Interaction>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    dimensions: Res<Dimensions>,
+) {
+    if editor_query.get_single().is_err()
+        || !buttons.just_pressed(MouseButton::Left)
+        || !is_alt_pressed(&keys)
+        || is_cursor_over_ui(&ui_query)
+    {
+        return;
+    }
+
+    let window = window_query
+        .get_single()
+        .expect("there should be only one window");
+
+    let transform = background_query
         .get_single()
         .expect("there should be only one background");
 
+    if let Some(position) = get_cursor_grid_position(window, transform, &dimensions)
+        .filter(|position| dimensions.contains(*position))
+    {
+        events.send(PlaytestEvent::Start(Some(position)));
+    }
+}
+
+/// Clicks while holding Alt are reserved for starting a playtest.
+pub(super) fn is_alt_pressed(keys: &ButtonInput<KeyCode>) -> bool {
+    keys.any_pressed([KeyCode::AltLeft, KeyCode::AltRight])
+}
+
+/// Clicks on panels that float on top of the board are not meant for the
+/// board either.
+pub(super) fn is_cursor_over_ui(ui_query: &Query<&Interaction>) -> bool {
+    ui_query
+        .iter()
+        .any(|interaction| *interaction != Interaction::None)
+}
+
+/// Returns the position on the board the cursor is pointing at, if any.
+pub(super) fn get_cursor_grid_position(
+    window: &Window,
+    transform: &Transform,
+    dimensions: &Dimensions,
+) -> Option<Position> {
+    let cursor_position = window.cursor_position()?;
+    let window_size = window.size();
+
+    // Clicks on the editor itself are not meant for the board.
+    if cursor_position.x >= window_size.x - EDITOR_WIDTH as f32 {
+        return None;
+    }
+
     let center_x = 0.5 * window_size.x + transform.translation.x;
     let x = ((cursor_position.x - center_x) / (transform.scale.x * GRID_SIZE as f32)
         + 0.5 * dimensions.width as f32) as i16
@@ -128,15 +380,40 @@ pub fn spawn_selected_object(
         + 0.5 * dimensions.height as f32) as i16
         + 1;
 
-    let position = Position { x, y };
+    Some(Position { x, y })
+}
 
-    for (entity, object_position) in &objects {
-        if *object_position == position {
+/// Replaces whatever the selected object replaces at the given position with
+/// the selected object, and records the replacement in the given change.
+#[allow(clippy::too_many_arguments)]
+fn place_object(
+    commands: &mut Commands,
+    background: Entity,
+    objects: &Query<EditorObjectQuery>,
+    position: Position,
+    selected_object_type: EditorObjectType,
+    replaces: &impl Fn(Option<&ObjectType>) -> bool,
+    dimensions: &Dimensions,
+    assets: &GameObjectAssets,
+    change: &mut EditorChange,
+) {
+    for (entity, object_position, object_type, direction, waypoints, channel) in objects {
+        if *object_position == position && replaces(object_type) {
             commands.entity(entity).despawn();
+
+            if let Some(object_type) = object_type {
+                change.removed.push(to_editor_object((
+                    object_type,
+                    object_position,
+                    direction,
+                    waypoints,
+                    channel,
+                )));
+            }
         }
     }
 
-    if x < 1 || x > dimensions.width || y < 1 || y > dimensions.height {
+    if !dimensions.contains(position) {
         return;
     }
 
@@ -144,7 +421,170 @@ pub fn spawn_selected_object(
         let mut background = commands.entity(background);
 
         background.with_children(|cb| {
-            spawn_object_of_type(cb, &assets, object_type, position, direction);
+            spawn_object_of_type(cb, assets, object_type, position, direction);
         });
+
+        change.added.push((
+            object_type,
+            InitialPositionAndDirection {
+                position,
+                direction: Some(direction),
+                waypoints: Vec::new(),
+                channel: Channel::default(),
+            },
+        ));
+    }
+}
+
+/// Returns the positions on a straight line between the given positions.
+fn line_positions(from: Position, to: Position) -> Vec<Position> {
+    let (dx, dy) = ((to.x - from.x).abs(), -(to.y - from.y).abs());
+    let (step_x, step_y) = ((to.x - from.x).signum(), (to.y - from.y).signum());
+
+    let mut positions = Vec::new();
+    let mut position = from;
+    let mut error = dx + dy;
+    loop {
+        positions.push(position);
+        if position == to {
+            return positions;
+        }
+
+        if 2 * error >= dy {
+            error += dy;
+            position.x += step_x;
+        }
+        if 2 * error <= dx {
+            error += dx;
+            position.y += step_y;
+        }
+    }
+}
+
+/// Returns the positions within the rectangle spanned by the given corners.
+fn rectangle_positions(from: Position, to: Position) -> Vec<Position> {
+    let mut positions = Vec::new();
+    for y in from.y.min(to.y)..=from.y.max(to.y) {
+        for x in from.x.min(to.x)..=from.x.max(to.x) {
+            positions.push(Position { x, y });
+        }
+    }
+    positions
+}
+
+/// Returns the contiguous region of positions that contain the same types of
+/// objects as the given position, only counting the objects that would be
+/// replaced.
+fn flood_fill_positions(
+    from: Position,
+    objects: &Query<EditorObjectQuery>,
+    replaces: &impl Fn(Option<&ObjectType>) -> bool,
+    dimensions: &Dimensions,
+) -> Vec<Position> {
+    if !dimensions.contains(from) {
+        return Vec::new();
     }
+
+    let mut contents: BTreeMap<Position, Vec<ObjectType>> = BTreeMap::new();
+    for (_, position, object_type, ..) in objects {
+        if let Some(object_type) = object_type.filter(|object_type| replaces(Some(object_type))) {
+            contents.entry(*position).or_default().push(*object_type);
+        }
+    }
+    for object_types in contents.values_mut() {
+        object_types.sort_unstable();
+    }
+
+    let region_contents = contents.get(&from).cloned().unwrap_or_default();
+    let mut positions = vec![from];
+    let mut visited = BTreeSet::from([from]);
+    let mut queue = VecDeque::from([from]);
+    while let Some(position) = queue.pop_front() {
+        for direction in [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ] {
+            let (dx, dy) = direction.to_delta();
+            let next = Position {
+                x: position.x + dx,
+                y: position.y + dy,
+            };
+            if dimensions.contains(next)
+                && !visited.contains(&next)
+                && contents.get(&next).cloned().unwrap_or_default() == region_contents
+            {
+                visited.insert(next);
+                positions.push(next);
+                queue.push_back(next);
+            }
+        }
+    }
+    positions
+}
+
+pub fn on_levels_changed(
+    mut text_query: Query<(&mut Text, Ref<LevelNumber>)>,
+    levels: Res<Levels>,
+) {
+    for (mut text, level_number) in &mut text_query {
+        if levels.is_changed() || level_number.is_added() {
+            text.sections[0].value =
+                format!("Level {}/{}", levels.current_level + 1, levels.levels.len());
+        }
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_editor_keyboard_input(
+    mut commands: Commands,
+    mut dimensions: ResMut<Dimensions>,
+    mut history: ResMut<EditorHistory>,
+    mut save_events: EventWriter<SaveLevelEvent>,
+    background_query: Query<Entity, With<Background>>,
+    editor_query: Query<Entity, With<Editor>>,
+    objects: Query<(Entity, &ObjectType, &Position)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    assets: Res<GameObjectAssets>,
+) {
+    if editor_query.get_single().is_err()
+        || !keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight])
+    {
+        return;
+    }
+
+    if keys.just_pressed(KeyCode::KeyS) {
+        save_events.send(SaveLevelEvent::Save);
+        return;
+    }
+
+    let shift_pressed = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let change = if keys.just_pressed(KeyCode::KeyY)
+        || (shift_pressed && keys.just_pressed(KeyCode::KeyZ))
+    {
+        history.redo()
+    } else if keys.just_pressed(KeyCode::KeyZ) {
+        history.undo()
+    } else {
+        None
+    };
+
+    let Some(change) = change else {
+        return;
+    };
+
+    let background = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    change.apply(
+        &mut commands,
+        background,
+        objects
+            .iter()
+            .map(|(entity, object_type, position)| (entity, *object_type, *position)),
+        &mut dimensions,
+        &assets,
+    );
 }
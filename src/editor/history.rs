@@ -0,0 +1,135 @@
+use bevy::prelude::*;
+
+use crate::{
+    game_object::{GameObjectAssets, ObjectType, Position},
+    level::{Dimensions, InitialPositionAndDirection},
+    spawn_level_objects, LevelObject,
+};
+
+/// An object on the board, in the form it is saved in.
+pub type EditorObject = (ObjectType, InitialPositionAndDirection);
+
+pub fn to_editor_object(
+    (object_type, position, direction, waypoints, channel): LevelObject,
+) -> EditorObject {
+    (
+        *object_type,
+        InitialPositionAndDirection {
+            position: *position,
+            direction: direction.copied(),
+            waypoints: waypoints
+                .map(|waypoints| waypoints.positions.clone())
+                .unwrap_or_default(),
+            channel: channel.copied().unwrap_or_default(),
+        },
+    )
+}
+
+/// A single change made in the editor, which can be undone and redone.
+///
+/// Changes that affect multiple objects at once are recorded as a single
+/// change, so they are also undone at once.
+#[derive(Clone, Default)]
+pub struct EditorChange {
+    /// Objects that were removed from the board.
+    pub removed: Vec<EditorObject>,
+
+    /// Objects that were added to the board.
+    pub added: Vec<EditorObject>,
+
+    /// Dimensions before and after the change, if the level was resized.
+    pub resized: Option<(Dimensions, Dimensions)>,
+}
+
+impl EditorChange {
+    pub fn is_empty(&self) -> bool {
+        self.removed.is_empty() && self.added.is_empty() && self.resized.is_none()
+    }
+
+    /// Returns the change that reverts this one.
+    pub fn inverse(self) -> Self {
+        Self {
+            removed: self.added,
+            added: self.removed,
+            resized: self.resized.map(|(before, after)| (after, before)),
+        }
+    }
+
+    /// Applies the change to the board.
+    ///
+    /// Removed objects are looked up by their type and position, because
+    /// entities don't survive being undone and redone.
+    pub fn apply(
+        self,
+        commands: &mut Commands,
+        background: Entity,
+        objects: impl Iterator<Item = (Entity, ObjectType, Position)>,
+        dimensions: &mut Dimensions,
+        assets: &GameObjectAssets,
+    ) {
+        let mut objects: Vec<_> = objects.collect();
+        for (object_type, object) in &self.removed {
+            if let Some(index) = objects
+                .iter()
+                .position(|(_, ty, position)| ty == object_type && *position == object.position)
+            {
+                let (entity, ..) = objects.swap_remove(index);
+                commands.entity(entity).despawn_recursive();
+            }
+        }
+
+        commands.entity(background).with_children(|cb| {
+            spawn_level_objects(
+                cb,
+                self.added
+                    .into_iter()
+                    .map(|(object_type, object)| (object_type, vec![object])),
+                assets,
+            );
+        });
+
+        if let Some((_, after)) = self.resized {
+            *dimensions = after;
+        }
+    }
+}
+
+/// History of the changes made in the editor for the current level.
+#[derive(Default, Resource)]
+pub struct EditorHistory {
+    undo_stack: Vec<EditorChange>,
+    redo_stack: Vec<EditorChange>,
+}
+
+impl EditorHistory {
+    pub fn clear(&mut self) {
+        self.undo_stack.clear();
+        self.redo_stack.clear();
+    }
+
+    /// Records a change that was just made. Any changes that were undone can
+    /// no longer be redone afterwards.
+    pub fn push(&mut self, change: EditorChange) {
+        if change.is_empty() {
+            return;
+        }
+
+        self.undo_stack.push(change);
+        self.redo_stack.clear();
+    }
+
+    /// Returns the change to apply for undoing the last change, if any.
+    pub fn undo(&mut self) -> Option<EditorChange> {
+        let change = self.undo_stack.pop()?;
+        self.redo_stack.push(change.clone());
+        Some(change.inverse())
+    }
+
+    /// Returns the change to apply for redoing the last undone change, if
+    /// any.
+    pub fn redo(&mut self) -> Option<EditorChange> {
+        let change = self.redo_stack.pop()?;
+        self.undo_stack.push(change.clone());
+        Some(change)
+    }
+}
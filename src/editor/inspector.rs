@@ -0,0 +1,191 @@
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{Channel, Direction, Position},
+};
+
+use super::{button::EditorButtonBundle, panel::PanelBundle, EditorObject, EditorObjectType};
+
+const DIRECTION_BUTTON_WIDTH: f32 = 56.;
+const CHANNEL_BUTTON_WIDTH: f32 = 30.;
+
+/// Panel showing the properties of the objects on the square that is selected
+/// with a single click of [EditorTool::Select].
+///
+/// [EditorTool::Select]: super::EditorTool::Select
+#[derive(Component)]
+pub struct Inspector;
+
+/// Change to the properties of the given object that is made by pressing a
+/// button in the inspector.
+#[derive(Clone, Component, Copy)]
+pub enum InspectorAction {
+    SetDirection(Entity, Direction),
+    SetChannel(Entity, Channel),
+    EditWaypoints(Entity),
+    ClearWaypoints(Entity),
+}
+
+/// Object whose waypoints are being added to by clicking squares on the board,
+/// if any.
+#[derive(Default, Resource)]
+pub struct WaypointEditing(pub Option<Entity>);
+
+#[derive(Bundle)]
+pub struct InspectorBundle {
+    background: PanelBundle,
+    inspector: Inspector,
+}
+
+impl InspectorBundle {
+    pub fn new() -> Self {
+        Self {
+            background: PanelBundle::new(
+                Style {
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                    top: Val::Px(10.),
+                    left: Val::Px(10.),
+                    row_gap: Val::Px(6.),
+                    ..Default::default()
+                },
+                LIGHT_GRAY,
+            ),
+            inspector: Inspector,
+        }
+    }
+
+    pub fn populate(
+        cb: &mut ChildBuilder,
+        position: Position,
+        objects: &[(Entity, EditorObject)],
+        waypoint_editing: &WaypointEditing,
+        fonts: &Fonts,
+    ) {
+        let text_style = TextStyle {
+            font: fonts.poppins_light.clone(),
+            font_size: 16.,
+            color: WHITE,
+        };
+
+        if objects.is_empty() {
+            cb.spawn(TextBundle::from_section(
+                format!("Nothing at {position}"),
+                text_style.clone(),
+            ));
+        }
+
+        for (entity, (object_type, object)) in objects {
+            cb.spawn(TextBundle::from_section(
+                format!("{object_type} at {}", object.position),
+                text_style.clone(),
+            ));
+
+            if EditorObjectType::is_directional(*object_type) {
+                cb.spawn(row()).with_children(|cb| {
+                    for direction in [
+                        Direction::Up,
+                        Direction::Right,
+                        Direction::Down,
+                        Direction::Left,
+                    ] {
+                        cb.spawn((
+                            EditorButtonBundle::with_width(
+                                InspectorAction::SetDirection(*entity, direction),
+                                DIRECTION_BUTTON_WIDTH,
+                            ),
+                            Outline::new(
+                                Val::Px(1.),
+                                Val::ZERO,
+                                if object.direction == Some(direction) {
+                                    RED
+                                } else {
+                                    Color::NONE
+                                },
+                            ),
+                        ))
+                        .with_children(|cb| {
+                            EditorButtonBundle::populate(cb, direction.to_string(), fonts)
+                        });
+                    }
+                });
+            }
+
+            if object_type.has_channel() {
+                let Channel(channel) = object.channel;
+                cb.spawn(row()).with_children(|cb| {
+                    cb.spawn(TextBundle::from_section(
+                        format!("Channel: {channel}"),
+                        text_style.clone(),
+                    ));
+
+                    for (label, channel) in
+                        [("-", channel.checked_sub(1)), ("+", channel.checked_add(1))]
+                    {
+                        let Some(channel) = channel else {
+                            continue;
+                        };
+                        cb.spawn(EditorButtonBundle::with_width(
+                            InspectorAction::SetChannel(*entity, Channel(channel)),
+                            CHANNEL_BUTTON_WIDTH,
+                        ))
+                        .with_children(|cb| EditorButtonBundle::populate(cb, label, fonts));
+                    }
+                });
+            }
+
+            if object_type.has_waypoints() || !object.waypoints.is_empty() {
+                let is_editing = waypoint_editing.0 == Some(*entity);
+                cb.spawn(row()).with_children(|cb| {
+                    cb.spawn(TextBundle::from_section(
+                        format!("Waypoints: {}", object.waypoints.len()),
+                        text_style.clone(),
+                    ));
+
+                    cb.spawn(EditorButtonBundle::with_width(
+                        InspectorAction::EditWaypoints(*entity),
+                        DIRECTION_BUTTON_WIDTH,
+                    ))
+                    .with_children(|cb| {
+                        EditorButtonBundle::populate(
+                            cb,
+                            if is_editing { "Done" } else { "Add" },
+                            fonts,
+                        )
+                    });
+
+                    if !object.waypoints.is_empty() {
+                        cb.spawn(EditorButtonBundle::with_width(
+                            InspectorAction::ClearWaypoints(*entity),
+                            DIRECTION_BUTTON_WIDTH,
+                        ))
+                        .with_children(|cb| EditorButtonBundle::populate(cb, "Clear", fonts));
+                    }
+                });
+
+                if is_editing {
+                    cb.spawn(TextBundle::from_section(
+                        "Click squares to add waypoints",
+                        TextStyle {
+                            color: LIGHT_GRAY,
+                            ..text_style.clone()
+                        },
+                    ));
+                }
+            }
+        }
+    }
+}
+
+fn row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(4.),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
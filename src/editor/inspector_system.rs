@@ -0,0 +1,240 @@
+use std::cmp::Reverse;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{Channel, Direction, GameObjectAssets, ObjectType, Position, Waypoints},
+    level::Dimensions,
+    spawn_level_objects, Background, LevelObject,
+};
+
+use super::{
+    get_cursor_grid_position, is_alt_pressed, is_cursor_over_ui, to_editor_object, EditorChange,
+    EditorHistory, Inspector, InspectorAction, InspectorBundle, Selection, VisibleLayers,
+    WaypointEditing,
+};
+
+/// Shows the inspector while a single square is selected, and rebuilds it
+/// whenever the objects on that square change.
+#[allow(clippy::too_many_arguments)]
+pub fn update_inspector(
+    mut commands: Commands,
+    mut inspected: Local<Option<(Position, Vec<Entity>)>>,
+    mut waypoint_editing: ResMut<WaypointEditing>,
+    inspector_query: Query<Entity, With<Inspector>>,
+    objects: Query<(Entity, LevelObject)>,
+    changed_waypoints: Query<(), Changed<Waypoints>>,
+    selection: Res<Selection>,
+    visible_layers: Res<VisibleLayers>,
+    fonts: Res<Fonts>,
+) {
+    let position = selection
+        .area
+        .filter(|area| area.width() == 1 && area.height() == 1)
+        .filter(|_| selection.drag_offset == (0, 0))
+        .map(|area| area.top_left);
+
+    let mut contents: Vec<_> = position
+        .map(|position| {
+            objects
+                .iter()
+                .filter(|(_, (object_type, object_position, ..))| {
+                    **object_position == position && visible_layers.is_editable(Some(object_type))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    contents.sort_by_key(|(_, (object_type, ..))| Reverse(object_type.layer()));
+
+    // Waypoints can only be added while their object is being inspected.
+    if waypoint_editing
+        .0
+        .is_some_and(|editing| !contents.iter().any(|(entity, _)| *entity == editing))
+    {
+        waypoint_editing.0 = None;
+    }
+
+    let new_inspected = position.map(|position| {
+        let entities = contents.iter().map(|(entity, _)| *entity).collect();
+        (position, entities)
+    });
+    let waypoints_changed = contents
+        .iter()
+        .any(|(entity, _)| changed_waypoints.contains(*entity));
+    if *inspected == new_inspected && !waypoint_editing.is_changed() && !waypoints_changed {
+        return;
+    }
+
+    for inspector in &inspector_query {
+        commands.entity(inspector).despawn_recursive();
+    }
+
+    if let Some(position) = position {
+        let contents: Vec<_> = contents
+            .into_iter()
+            .map(|(entity, object)| (entity, to_editor_object(object)))
+            .collect();
+
+        commands.spawn(InspectorBundle::new()).with_children(|cb| {
+            InspectorBundle::populate(cb, position, &contents, &waypoint_editing, &fonts)
+        });
+    }
+
+    *inspected = new_inspected;
+}
+
+pub fn on_inspector_input(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &InspectorAction, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut history: ResMut<EditorHistory>,
+    mut waypoint_editing: ResMut<WaypointEditing>,
+    background_query: Query<Entity, With<Background>>,
+    objects: Query<LevelObject>,
+    assets: Res<GameObjectAssets>,
+) {
+    for (interaction, action, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = WHITE.into();
+
+                let entity = match *action {
+                    InspectorAction::EditWaypoints(entity) => {
+                        waypoint_editing.0 = if waypoint_editing.0 == Some(entity) {
+                            None
+                        } else {
+                            Some(entity)
+                        };
+                        continue;
+                    }
+                    InspectorAction::SetDirection(entity, _)
+                    | InspectorAction::SetChannel(entity, _)
+                    | InspectorAction::ClearWaypoints(entity) => entity,
+                };
+                let Ok(object) = objects.get(entity) else {
+                    continue;
+                };
+
+                let removed = to_editor_object(object);
+                let (object_type, mut object) = removed.clone();
+                match *action {
+                    InspectorAction::SetDirection(_, direction) => {
+                        object.direction = Some(direction);
+                    }
+                    InspectorAction::SetChannel(_, channel) => {
+                        object.channel = channel;
+                    }
+                    InspectorAction::ClearWaypoints(_) => {
+                        object.waypoints.clear();
+                    }
+                    InspectorAction::EditWaypoints(_) => continue,
+                }
+
+                // Objects are replaced rather than updated in place, so they
+                // get spawned with everything that depends on their properties.
+                commands.entity(entity).despawn_recursive();
+
+                let background = background_query
+                    .get_single()
+                    .expect("there should be only one background");
+
+                commands.entity(background).with_children(|cb| {
+                    spawn_level_objects(cb, [(object_type, vec![object.clone()])], &assets);
+                });
+
+                history.push(EditorChange {
+                    removed: vec![removed],
+                    added: vec![(object_type, object)],
+                    ..Default::default()
+                });
+            }
+            Interaction::Hovered => {
+                *color = LIGHT_GRAY.into();
+            }
+            Interaction::None => {
+                *color = DARK_GRAY.into();
+            }
+        }
+    }
+}
+
+/// Adds the squares that are clicked on the board to the waypoints of the
+/// object whose waypoints are being edited in the inspector.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn add_waypoint(
+    mut commands: Commands,
+    mut history: ResMut<EditorHistory>,
+    mut waypoint_editing: ResMut<WaypointEditing>,
+    mut objects: Query<(
+        &ObjectType,
+        &Position,
+        Option<&Direction>,
+        Option<&mut Waypoints>,
+        Option<&Channel>,
+    )>,
+    background_query: Query<&Transform, With<Background>>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_query: Query<&Interaction>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    dimensions: Res<Dimensions>,
+) {
+    let Some(entity) = waypoint_editing.0 else {
+        return;
+    };
+
+    if !buttons.just_pressed(MouseButton::Left)
+        || is_alt_pressed(&keys)
+        || is_cursor_over_ui(&ui_query)
+    {
+        return;
+    }
+
+    let window = window_query
+        .get_single()
+        .expect("there should be only one window");
+
+    let transform = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let Some(position) = get_cursor_grid_position(window, transform, &dimensions)
+        .filter(|position| dimensions.contains(*position))
+    else {
+        return;
+    };
+
+    let Ok((object_type, object_position, direction, waypoints, channel)) = objects.get_mut(entity)
+    else {
+        waypoint_editing.0 = None;
+        return;
+    };
+
+    let removed = to_editor_object((
+        object_type,
+        object_position,
+        direction,
+        waypoints.as_deref(),
+        channel,
+    ));
+    match waypoints {
+        Some(mut waypoints) => waypoints.positions.push(position),
+        None => {
+            commands
+                .entity(entity)
+                .insert(Waypoints::new(vec![position]));
+        }
+    }
+
+    let (object_type, mut object) = removed.clone();
+    object.waypoints.push(position);
+    history.push(EditorChange {
+        removed: vec![removed],
+        added: vec![(object_type, object)],
+        ..Default::default()
+    });
+}
@@ -0,0 +1,130 @@
+use std::collections::BTreeSet;
+
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{Layer, ObjectType},
+};
+
+use super::{EditorObjectType, TOOL_OUTLINE_WIDTH};
+
+/// Layers that are shown in the editor. Objects on hidden layers cannot be
+/// edited.
+#[derive(Resource)]
+pub struct VisibleLayers(BTreeSet<Layer>);
+
+impl VisibleLayers {
+    pub fn contains(&self, layer: Layer) -> bool {
+        self.0.contains(&layer)
+    }
+
+    /// Returns whether objects of the given type can be edited. Entities
+    /// without an object type, such as graves, can always be removed.
+    pub fn is_editable(&self, object_type: Option<&ObjectType>) -> bool {
+        object_type.is_none_or(|object_type| self.contains(object_type.layer()))
+    }
+
+    /// Returns whether the given palette entry can be placed. Objects cannot be
+    /// placed on hidden layers, while the eraser can always be used.
+    pub fn can_place(&self, object_type: EditorObjectType) -> bool {
+        object_type
+            .get_object_type_and_direction()
+            .is_none_or(|(object_type, _)| self.contains(object_type.layer()))
+    }
+
+    pub fn toggle(&mut self, layer: Layer) {
+        if !self.0.remove(&layer) {
+            self.0.insert(layer);
+        }
+    }
+}
+
+impl Default for VisibleLayers {
+    fn default() -> Self {
+        Self(Layer::ALL.into())
+    }
+}
+
+#[derive(Clone, Component, Copy)]
+pub struct LayerToggle(pub Layer);
+
+impl LayerToggle {
+    fn label(self) -> &'static str {
+        match self.0 {
+            Layer::Floor => "Floor",
+            Layer::Item => "Items",
+            Layer::Object => "Objects",
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LayerSelectorBundle {
+    node: NodeBundle,
+}
+
+impl LayerSelectorBundle {
+    pub fn new() -> Self {
+        Self {
+            node: NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Px(26.),
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn populate(cb: &mut ChildBuilder, visible_layers: &VisibleLayers, fonts: &Fonts) {
+        for layer in Layer::ALL {
+            let toggle = LayerToggle(layer);
+            cb.spawn((
+                toggle,
+                ButtonBundle {
+                    background_color: NORMAL_GRAY.into(),
+                    border_radius: BorderRadius::all(Val::Px(4.)),
+                    style: Style {
+                        height: Val::Px(26.),
+                        width: Val::Px(64.),
+                        align_content: AlignContent::Center,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Outline::new(
+                    Val::Px(TOOL_OUTLINE_WIDTH),
+                    Val::ZERO,
+                    if visible_layers.contains(layer) {
+                        BLUE
+                    } else {
+                        Color::NONE
+                    },
+                ),
+            ))
+            .with_children(|cb| {
+                cb.spawn(TextBundle {
+                    text: Text::from_section(
+                        toggle.label(),
+                        TextStyle {
+                            font: fonts.poppins_light.clone(),
+                            font_size: 13.,
+                            color: WHITE,
+                        },
+                    ),
+                    style: Style {
+                        margin: UiRect::all(Val::Auto),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            });
+        }
+    }
+}
@@ -0,0 +1,65 @@
+use bevy::prelude::*;
+
+use crate::{constants::*, game_object::ObjectType};
+
+use super::{Editor, LayerToggle, VisibleLayers};
+
+pub fn on_layer_toggle_input(
+    mut interaction_query: Query<
+        (&Interaction, &LayerToggle, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut visible_layers: ResMut<VisibleLayers>,
+) {
+    for (interaction, toggle, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = WHITE.into();
+
+                visible_layers.toggle(toggle.0);
+            }
+            Interaction::Hovered => {
+                *color = LIGHT_GRAY.into();
+            }
+            Interaction::None => {
+                *color = NORMAL_GRAY.into();
+            }
+        }
+    }
+}
+
+pub fn on_visible_layers_change(
+    mut toggle_query: Query<(&LayerToggle, &mut Outline)>,
+    visible_layers: Res<VisibleLayers>,
+) {
+    if !visible_layers.is_changed() {
+        return;
+    }
+
+    for (toggle, mut outline) in &mut toggle_query {
+        outline.color = if visible_layers.contains(toggle.0) {
+            BLUE
+        } else {
+            Color::NONE
+        };
+    }
+}
+
+/// Hides the objects on hidden layers while the editor is open.
+pub fn update_layer_visibility(
+    mut object_query: Query<(&ObjectType, &mut Visibility)>,
+    editor_query: Query<Entity, With<Editor>>,
+    visible_layers: Res<VisibleLayers>,
+) {
+    let editor_open = editor_query.get_single().is_ok();
+    for (object_type, mut visibility) in &mut object_query {
+        let new_visibility = if !editor_open || visible_layers.contains(object_type.layer()) {
+            Visibility::Inherited
+        } else {
+            Visibility::Hidden
+        };
+        if *visibility != new_visibility {
+            *visibility = new_visibility;
+        }
+    }
+}
@@ -4,16 +4,21 @@ use bevy::prelude::*;
 
 use crate::{
     constants::*,
-    game_object::{Direction, GameObjectAssets, ObjectType},
+    game_object::{Direction, GameObjectAssets, ObjectType, Position},
+    level::Dimensions,
 };
 
 const NUM_OBJECTS: i16 = EditorObjectType::__Last as i16;
-const NUM_COLUMNS: i16 = EDITOR_WIDTH / GRID_SIZE;
+pub const SELECTOR_OUTLINE_WIDTH: i16 = 1;
+const SELECTOR_CELL_SIZE: i16 = 32;
+const NUM_COLUMNS: i16 =
+    (EDITOR_WIDTH - 2 * EDITOR_PADDING) / (SELECTOR_CELL_SIZE + SELECTOR_OUTLINE_WIDTH);
 const NUM_ROWS: i16 =
     NUM_OBJECTS / NUM_COLUMNS + if NUM_OBJECTS % NUM_COLUMNS == 0 { 0 } else { 1 };
-pub const SELECTOR_OUTLINE_WIDTH: i16 = 1;
-const SELECTOR_WIDTH: i16 = NUM_COLUMNS * GRID_SIZE + (NUM_COLUMNS - 1) * SELECTOR_OUTLINE_WIDTH;
-const SELECTOR_HEIGHT: i16 = NUM_ROWS * GRID_SIZE + (NUM_ROWS - 1) * SELECTOR_OUTLINE_WIDTH;
+const SELECTOR_WIDTH: i16 =
+    NUM_COLUMNS * SELECTOR_CELL_SIZE + (NUM_COLUMNS - 1) * SELECTOR_OUTLINE_WIDTH;
+const SELECTOR_HEIGHT: i16 =
+    NUM_ROWS * SELECTOR_CELL_SIZE + (NUM_ROWS - 1) * SELECTOR_OUTLINE_WIDTH;
 
 #[derive(Component)]
 pub struct ObjectSelector;
@@ -38,23 +43,76 @@ impl DerefMut for SelectedObjectType {
 #[derive(Clone, Component, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum EditorObjectType {
     Eraser,
+    BackslashMirror,
     BlueBlock,
+    Bomb,
+    Boulder,
     BouncingBallUp,
     BouncingBallRight,
     BouncingBallDown,
     BouncingBallLeft,
     Button,
+    Checkpoint,
+    CrackedFloor,
     Creature1Up,
     Creature1Right,
     Creature1Down,
     Creature1Left,
+    Creature2Up,
+    Creature2Right,
+    Creature2Down,
+    Creature2Left,
+    Creature3Up,
+    Creature3Right,
+    Creature3Down,
+    Creature3Left,
+    Creature4Up,
+    Creature4Right,
+    Creature4Down,
+    Creature4Left,
+    Creature5Up,
+    Creature5Right,
+    Creature5Down,
+    Creature5Left,
+    DestructibleWall,
     Exit,
+    FlowingWaterUp,
+    FlowingWaterRight,
+    FlowingWaterDown,
+    FlowingWaterLeft,
     Gate,
+    Gem,
+    HeavyPressurePlate,
+    LaserEmitterUp,
+    LaserEmitterRight,
+    LaserEmitterDown,
+    LaserEmitterLeft,
+    Lava,
+    LockedExit,
     Mine,
+    OneWayUp,
+    OneWayRight,
+    OneWayDown,
+    OneWayLeft,
+    Pit,
     Player,
+    PressurePlate,
     PurpleBlock,
     Raft,
+    Receiver,
     RedBlock,
+    ReversingTransporterUp,
+    ReversingTransporterRight,
+    ReversingTransporterDown,
+    ReversingTransporterLeft,
+    RotatingTransporterUp,
+    RotatingTransporterRight,
+    RotatingTransporterDown,
+    RotatingTransporterLeft,
+    ShallowWater,
+    SlashMirror,
+    SunkenBlock,
+    Swamp,
     TransporterUp,
     TransporterRight,
     TransporterDown,
@@ -67,23 +125,75 @@ pub enum EditorObjectType {
 impl EditorObjectType {
     pub fn get_object_type_and_direction(self) -> Option<(ObjectType, Direction)> {
         let object_type = match self {
+            Self::BackslashMirror => Some(ObjectType::BackslashMirror),
             Self::BlueBlock => Some(ObjectType::BlueBlock),
+            Self::Bomb => Some(ObjectType::Bomb),
+            Self::Boulder => Some(ObjectType::Boulder),
             Self::BouncingBallUp
             | Self::BouncingBallRight
             | Self::BouncingBallDown
             | Self::BouncingBallLeft => Some(ObjectType::BouncingBall),
             Self::Button => Some(ObjectType::Button),
+            Self::Checkpoint => Some(ObjectType::Checkpoint),
+            Self::CrackedFloor => Some(ObjectType::CrackedFloor),
             Self::Creature1Up
             | Self::Creature1Right
             | Self::Creature1Down
             | Self::Creature1Left => Some(ObjectType::Creature1),
+            Self::Creature2Up
+            | Self::Creature2Right
+            | Self::Creature2Down
+            | Self::Creature2Left => Some(ObjectType::Creature2),
+            Self::Creature3Up
+            | Self::Creature3Right
+            | Self::Creature3Down
+            | Self::Creature3Left => Some(ObjectType::Creature3),
+            Self::Creature4Up
+            | Self::Creature4Right
+            | Self::Creature4Down
+            | Self::Creature4Left => Some(ObjectType::Creature4),
+            Self::Creature5Up
+            | Self::Creature5Right
+            | Self::Creature5Down
+            | Self::Creature5Left => Some(ObjectType::Creature5),
+            Self::DestructibleWall => Some(ObjectType::DestructibleWall),
             Self::Exit => Some(ObjectType::Exit),
+            Self::FlowingWaterUp
+            | Self::FlowingWaterRight
+            | Self::FlowingWaterDown
+            | Self::FlowingWaterLeft => Some(ObjectType::FlowingWater),
             Self::Gate => Some(ObjectType::Gate),
+            Self::Gem => Some(ObjectType::Gem),
+            Self::HeavyPressurePlate => Some(ObjectType::HeavyPressurePlate),
+            Self::LaserEmitterUp
+            | Self::LaserEmitterRight
+            | Self::LaserEmitterDown
+            | Self::LaserEmitterLeft => Some(ObjectType::LaserEmitter),
+            Self::Lava => Some(ObjectType::Lava),
+            Self::LockedExit => Some(ObjectType::LockedExit),
             Self::Mine => Some(ObjectType::Mine),
+            Self::OneWayUp | Self::OneWayRight | Self::OneWayDown | Self::OneWayLeft => {
+                Some(ObjectType::OneWay)
+            }
+            Self::Pit => Some(ObjectType::Pit),
             Self::Player => Some(ObjectType::Player),
+            Self::PressurePlate => Some(ObjectType::PressurePlate),
             Self::PurpleBlock => Some(ObjectType::PurpleBlock),
             Self::Raft => Some(ObjectType::Raft),
+            Self::Receiver => Some(ObjectType::Receiver),
             Self::RedBlock => Some(ObjectType::RedBlock),
+            Self::ReversingTransporterUp
+            | Self::ReversingTransporterRight
+            | Self::ReversingTransporterDown
+            | Self::ReversingTransporterLeft => Some(ObjectType::ReversingTransporter),
+            Self::RotatingTransporterUp
+            | Self::RotatingTransporterRight
+            | Self::RotatingTransporterDown
+            | Self::RotatingTransporterLeft => Some(ObjectType::RotatingTransporter),
+            Self::ShallowWater => Some(ObjectType::ShallowWater),
+            Self::SlashMirror => Some(ObjectType::SlashMirror),
+            Self::SunkenBlock => Some(ObjectType::SunkenBlock),
+            Self::Swamp => Some(ObjectType::Swamp),
             Self::TransporterUp
             | Self::TransporterRight
             | Self::TransporterDown
@@ -102,6 +212,42 @@ impl EditorObjectType {
             Self::Creature1Right => Direction::Right,
             Self::Creature1Down => Direction::Down,
             Self::Creature1Left => Direction::Left,
+            Self::Creature2Up => Direction::Up,
+            Self::Creature2Right => Direction::Right,
+            Self::Creature2Down => Direction::Down,
+            Self::Creature2Left => Direction::Left,
+            Self::Creature3Up => Direction::Up,
+            Self::Creature3Right => Direction::Right,
+            Self::Creature3Down => Direction::Down,
+            Self::Creature3Left => Direction::Left,
+            Self::Creature4Up => Direction::Up,
+            Self::Creature4Right => Direction::Right,
+            Self::Creature4Down => Direction::Down,
+            Self::Creature4Left => Direction::Left,
+            Self::Creature5Up => Direction::Up,
+            Self::Creature5Right => Direction::Right,
+            Self::Creature5Down => Direction::Down,
+            Self::Creature5Left => Direction::Left,
+            Self::FlowingWaterUp => Direction::Up,
+            Self::FlowingWaterRight => Direction::Right,
+            Self::FlowingWaterDown => Direction::Down,
+            Self::FlowingWaterLeft => Direction::Left,
+            Self::LaserEmitterUp => Direction::Up,
+            Self::LaserEmitterRight => Direction::Right,
+            Self::LaserEmitterDown => Direction::Down,
+            Self::LaserEmitterLeft => Direction::Left,
+            Self::OneWayUp => Direction::Up,
+            Self::OneWayRight => Direction::Right,
+            Self::OneWayDown => Direction::Down,
+            Self::OneWayLeft => Direction::Left,
+            Self::ReversingTransporterUp => Direction::Up,
+            Self::ReversingTransporterRight => Direction::Right,
+            Self::ReversingTransporterDown => Direction::Down,
+            Self::ReversingTransporterLeft => Direction::Left,
+            Self::RotatingTransporterUp => Direction::Up,
+            Self::RotatingTransporterRight => Direction::Right,
+            Self::RotatingTransporterDown => Direction::Down,
+            Self::RotatingTransporterLeft => Direction::Left,
             Self::TransporterUp => Direction::Up,
             Self::TransporterRight => Direction::Right,
             Self::TransporterDown => Direction::Down,
@@ -112,26 +258,160 @@ impl EditorObjectType {
         object_type.map(|object_type| (object_type, direction))
     }
 
+    /// Returns the key that selects the entry, if any.
+    ///
+    /// Keys that are already used for playing, such as E and R, are left
+    /// out. Objects that face a direction can be turned after selecting them.
+    pub fn get_hotkey(self) -> Option<KeyCode> {
+        let key = match self {
+            Self::Eraser => KeyCode::Digit0,
+            Self::Player => KeyCode::Digit1,
+            Self::Exit => KeyCode::Digit2,
+            Self::RedBlock => KeyCode::Digit3,
+            Self::BlueBlock => KeyCode::Digit4,
+            Self::PurpleBlock => KeyCode::Digit5,
+            Self::YellowBlock => KeyCode::Digit6,
+            Self::Water => KeyCode::Digit7,
+            Self::Gate => KeyCode::Digit8,
+            Self::Button => KeyCode::Digit9,
+            Self::Bomb => KeyCode::KeyB,
+            Self::DestructibleWall => KeyCode::KeyD,
+            Self::FlowingWaterUp => KeyCode::KeyF,
+            Self::Gem => KeyCode::KeyG,
+            Self::Checkpoint => KeyCode::KeyK,
+            Self::Lava => KeyCode::KeyL,
+            Self::Mine => KeyCode::KeyM,
+            Self::OneWayUp => KeyCode::KeyO,
+            Self::PressurePlate => KeyCode::KeyP,
+            Self::Raft => KeyCode::KeyQ,
+            Self::TransporterUp => KeyCode::KeyT,
+            Self::Boulder => KeyCode::KeyU,
+            Self::Creature1Up => KeyCode::KeyV,
+            Self::Pit => KeyCode::KeyX,
+            _ => return None,
+        };
+        Some(key)
+    }
+
+    /// Returns the entry that is selected with the given key, if any.
+    pub fn from_hotkey(key: KeyCode) -> Option<Self> {
+        (0..NUM_OBJECTS)
+            .filter_map(|i| Self::try_from(i).ok())
+            .find(|editor_object_type| editor_object_type.get_hotkey() == Some(key))
+    }
+
+    /// Returns the entry for the given object facing the given direction, if
+    /// the object can be placed facing that direction.
+    pub fn from_object_type_and_direction(
+        object_type: ObjectType,
+        direction: Direction,
+    ) -> Option<Self> {
+        (0..NUM_OBJECTS)
+            .filter_map(|i| Self::try_from(i).ok())
+            .find(|editor_object_type| {
+                editor_object_type.get_object_type_and_direction() == Some((object_type, direction))
+            })
+    }
+
+    /// Returns whether the given object can be placed facing any direction.
+    pub fn is_directional(object_type: ObjectType) -> bool {
+        [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]
+        .into_iter()
+        .all(|direction| Self::from_object_type_and_direction(object_type, direction).is_some())
+    }
+
+    /// Returns the entry for the same object after turning it a quarter turn,
+    /// or the entry itself if the object has no direction.
+    pub fn rotate(self, clockwise: bool) -> Self {
+        let Some((object_type, direction)) = self.get_object_type_and_direction() else {
+            return self;
+        };
+
+        let direction = if clockwise {
+            direction.right_hand()
+        } else {
+            direction.left_hand()
+        };
+        Self::from_object_type_and_direction(object_type, direction).unwrap_or(self)
+    }
+
     fn get_texture(self, assets: &GameObjectAssets) -> (Handle<Image>, Option<TextureAtlas>) {
         let image = match self {
             Self::Eraser => assets.eraser.clone(),
+            Self::BackslashMirror => assets.backslash_mirror.clone(),
             Self::BlueBlock => assets.blue_block.clone(),
+            Self::Bomb => assets.bomb.clone(),
+            Self::Boulder => assets.boulder.clone(),
             Self::BouncingBallUp
             | Self::BouncingBallRight
             | Self::BouncingBallDown
             | Self::BouncingBallLeft => assets.bouncing_ball_editor.0.clone(),
             Self::Button => assets.button.clone(),
-            Self::Creature1Up => assets.creature1.0.clone(),
-            Self::Creature1Right => assets.creature1.0.clone(),
-            Self::Creature1Down => assets.creature1.0.clone(),
-            Self::Creature1Left => assets.creature1.0.clone(),
+            Self::Checkpoint => assets.checkpoint.0.clone(),
+            Self::CrackedFloor => assets.cracked_floor.clone(),
+            Self::Creature1Up
+            | Self::Creature1Right
+            | Self::Creature1Down
+            | Self::Creature1Left => assets.creature1.0.clone(),
+            Self::Creature2Up
+            | Self::Creature2Right
+            | Self::Creature2Down
+            | Self::Creature2Left => assets.creature2.0.clone(),
+            Self::Creature3Up
+            | Self::Creature3Right
+            | Self::Creature3Down
+            | Self::Creature3Left => assets.creature3.0.clone(),
+            Self::Creature4Up
+            | Self::Creature4Right
+            | Self::Creature4Down
+            | Self::Creature4Left => assets.creature4.0.clone(),
+            Self::Creature5Up
+            | Self::Creature5Right
+            | Self::Creature5Down
+            | Self::Creature5Left => assets.creature5.0.clone(),
+            Self::DestructibleWall => assets.destructible_wall.clone(),
             Self::Exit => assets.exit.clone(),
+            Self::FlowingWaterUp
+            | Self::FlowingWaterRight
+            | Self::FlowingWaterDown
+            | Self::FlowingWaterLeft => assets.flowing_water.0.clone(),
             Self::Gate => assets.gate.0.clone(),
+            Self::Gem => assets.gem.clone(),
+            Self::HeavyPressurePlate => assets.heavy_pressure_plate.clone(),
+            Self::LaserEmitterUp
+            | Self::LaserEmitterRight
+            | Self::LaserEmitterDown
+            | Self::LaserEmitterLeft => assets.laser_emitter.0.clone(),
+            Self::Lava => assets.lava.0.clone(),
+            Self::LockedExit => assets.locked_exit.0.clone(),
             Self::Mine => assets.mine.clone(),
+            Self::OneWayUp | Self::OneWayRight | Self::OneWayDown | Self::OneWayLeft => {
+                assets.one_way.0.clone()
+            }
+            Self::Pit => assets.pit.clone(),
             Self::Player => assets.player.clone(),
+            Self::PressurePlate => assets.pressure_plate.clone(),
             Self::PurpleBlock => assets.purple_block.clone(),
             Self::Raft => assets.raft.clone(),
+            Self::Receiver => assets.receiver.0.clone(),
             Self::RedBlock => assets.red_block.clone(),
+            Self::ReversingTransporterUp
+            | Self::ReversingTransporterRight
+            | Self::ReversingTransporterDown
+            | Self::ReversingTransporterLeft => assets.reversing_transporter.0.clone(),
+            Self::RotatingTransporterUp
+            | Self::RotatingTransporterRight
+            | Self::RotatingTransporterDown
+            | Self::RotatingTransporterLeft => assets.rotating_transporter.0.clone(),
+            Self::ShallowWater => assets.shallow_water.0.clone(),
+            Self::SlashMirror => assets.slash_mirror.clone(),
+            Self::SunkenBlock => assets.sunken_block.clone(),
+            Self::Swamp => assets.swamp.0.clone(),
             Self::TransporterUp
             | Self::TransporterRight
             | Self::TransporterDown
@@ -174,10 +454,166 @@ impl EditorObjectType {
                 layout: assets.creature1.1.clone(),
                 index: 3,
             }),
+            Self::Creature2Up => Some(TextureAtlas {
+                layout: assets.creature2.1.clone(),
+                index: 0,
+            }),
+            Self::Creature2Right => Some(TextureAtlas {
+                layout: assets.creature2.1.clone(),
+                index: 1,
+            }),
+            Self::Creature2Down => Some(TextureAtlas {
+                layout: assets.creature2.1.clone(),
+                index: 2,
+            }),
+            Self::Creature2Left => Some(TextureAtlas {
+                layout: assets.creature2.1.clone(),
+                index: 3,
+            }),
+            Self::Creature3Up => Some(TextureAtlas {
+                layout: assets.creature3.1.clone(),
+                index: 0,
+            }),
+            Self::Creature3Right => Some(TextureAtlas {
+                layout: assets.creature3.1.clone(),
+                index: 1,
+            }),
+            Self::Creature3Down => Some(TextureAtlas {
+                layout: assets.creature3.1.clone(),
+                index: 2,
+            }),
+            Self::Creature3Left => Some(TextureAtlas {
+                layout: assets.creature3.1.clone(),
+                index: 3,
+            }),
+            Self::Creature4Up => Some(TextureAtlas {
+                layout: assets.creature4.1.clone(),
+                index: 0,
+            }),
+            Self::Creature4Right => Some(TextureAtlas {
+                layout: assets.creature4.1.clone(),
+                index: 1,
+            }),
+            Self::Creature4Down => Some(TextureAtlas {
+                layout: assets.creature4.1.clone(),
+                index: 2,
+            }),
+            Self::Creature4Left => Some(TextureAtlas {
+                layout: assets.creature4.1.clone(),
+                index: 3,
+            }),
+            Self::Creature5Up => Some(TextureAtlas {
+                layout: assets.creature5.1.clone(),
+                index: 0,
+            }),
+            Self::Creature5Right => Some(TextureAtlas {
+                layout: assets.creature5.1.clone(),
+                index: 1,
+            }),
+            Self::Creature5Down => Some(TextureAtlas {
+                layout: assets.creature5.1.clone(),
+                index: 2,
+            }),
+            Self::Creature5Left => Some(TextureAtlas {
+                layout: assets.creature5.1.clone(),
+                index: 3,
+            }),
+            Self::FlowingWaterUp => Some(TextureAtlas {
+                layout: assets.flowing_water.1.clone(),
+                index: 0,
+            }),
+            Self::FlowingWaterRight => Some(TextureAtlas {
+                layout: assets.flowing_water.1.clone(),
+                index: 1,
+            }),
+            Self::FlowingWaterDown => Some(TextureAtlas {
+                layout: assets.flowing_water.1.clone(),
+                index: 2,
+            }),
+            Self::FlowingWaterLeft => Some(TextureAtlas {
+                layout: assets.flowing_water.1.clone(),
+                index: 3,
+            }),
+            Self::Checkpoint => Some(TextureAtlas {
+                layout: assets.checkpoint.1.clone(),
+                index: 0,
+            }),
+            Self::Receiver => Some(TextureAtlas {
+                layout: assets.receiver.1.clone(),
+                index: 0,
+            }),
             Self::Gate => Some(TextureAtlas {
                 layout: assets.gate.1.clone(),
                 index: 0,
             }),
+            Self::LaserEmitterUp => Some(TextureAtlas {
+                layout: assets.laser_emitter.1.clone(),
+                index: 0,
+            }),
+            Self::LaserEmitterRight => Some(TextureAtlas {
+                layout: assets.laser_emitter.1.clone(),
+                index: 1,
+            }),
+            Self::LaserEmitterDown => Some(TextureAtlas {
+                layout: assets.laser_emitter.1.clone(),
+                index: 2,
+            }),
+            Self::LaserEmitterLeft => Some(TextureAtlas {
+                layout: assets.laser_emitter.1.clone(),
+                index: 3,
+            }),
+            Self::LockedExit => Some(TextureAtlas {
+                layout: assets.locked_exit.1.clone(),
+                index: 0,
+            }),
+            Self::OneWayUp => Some(TextureAtlas {
+                layout: assets.one_way.1.clone(),
+                index: 0,
+            }),
+            Self::OneWayRight => Some(TextureAtlas {
+                layout: assets.one_way.1.clone(),
+                index: 1,
+            }),
+            Self::OneWayDown => Some(TextureAtlas {
+                layout: assets.one_way.1.clone(),
+                index: 2,
+            }),
+            Self::OneWayLeft => Some(TextureAtlas {
+                layout: assets.one_way.1.clone(),
+                index: 3,
+            }),
+            Self::ReversingTransporterUp => Some(TextureAtlas {
+                layout: assets.reversing_transporter.1.clone(),
+                index: 0,
+            }),
+            Self::ReversingTransporterRight => Some(TextureAtlas {
+                layout: assets.reversing_transporter.1.clone(),
+                index: 1,
+            }),
+            Self::ReversingTransporterDown => Some(TextureAtlas {
+                layout: assets.reversing_transporter.1.clone(),
+                index: 2,
+            }),
+            Self::ReversingTransporterLeft => Some(TextureAtlas {
+                layout: assets.reversing_transporter.1.clone(),
+                index: 3,
+            }),
+            Self::RotatingTransporterUp => Some(TextureAtlas {
+                layout: assets.rotating_transporter.1.clone(),
+                index: 0,
+            }),
+            Self::RotatingTransporterRight => Some(TextureAtlas {
+                layout: assets.rotating_transporter.1.clone(),
+                index: 1,
+            }),
+            Self::RotatingTransporterDown => Some(TextureAtlas {
+                layout: assets.rotating_transporter.1.clone(),
+                index: 2,
+            }),
+            Self::RotatingTransporterLeft => Some(TextureAtlas {
+                layout: assets.rotating_transporter.1.clone(),
+                index: 3,
+            }),
             Self::TransporterUp => Some(TextureAtlas {
                 layout: assets.transporter.1.clone(),
                 index: 0,
@@ -194,6 +630,18 @@ impl EditorObjectType {
                 layout: assets.transporter.1.clone(),
                 index: 3,
             }),
+            Self::Lava => Some(TextureAtlas {
+                layout: assets.lava.1.clone(),
+                index: 0,
+            }),
+            Self::ShallowWater => Some(TextureAtlas {
+                layout: assets.shallow_water.1.clone(),
+                index: 0,
+            }),
+            Self::Swamp => Some(TextureAtlas {
+                layout: assets.swamp.1.clone(),
+                index: 0,
+            }),
             Self::Water => Some(TextureAtlas {
                 layout: assets.water.1.clone(),
                 index: 0,
@@ -234,6 +682,59 @@ impl TryFrom<i16> for EditorObjectType {
             21 => Self::Button,
             22 => Self::PurpleBlock,
             23 => Self::YellowBlock,
+            24 => Self::CrackedFloor,
+            25 => Self::Pit,
+            26 => Self::OneWayUp,
+            27 => Self::OneWayRight,
+            28 => Self::OneWayDown,
+            29 => Self::OneWayLeft,
+            30 => Self::PressurePlate,
+            31 => Self::HeavyPressurePlate,
+            32 => Self::Creature2Up,
+            33 => Self::Creature2Right,
+            34 => Self::Creature2Down,
+            35 => Self::Creature2Left,
+            36 => Self::Creature3Up,
+            37 => Self::Creature3Right,
+            38 => Self::Creature3Down,
+            39 => Self::Creature3Left,
+            40 => Self::Creature4Up,
+            41 => Self::Creature4Right,
+            42 => Self::Creature4Down,
+            43 => Self::Creature4Left,
+            44 => Self::Creature5Up,
+            45 => Self::Creature5Right,
+            46 => Self::Creature5Down,
+            47 => Self::Creature5Left,
+            48 => Self::FlowingWaterUp,
+            49 => Self::FlowingWaterRight,
+            50 => Self::FlowingWaterDown,
+            51 => Self::FlowingWaterLeft,
+            52 => Self::Swamp,
+            53 => Self::SunkenBlock,
+            54 => Self::ShallowWater,
+            55 => Self::Lava,
+            56 => Self::Bomb,
+            57 => Self::DestructibleWall,
+            58 => Self::Gem,
+            59 => Self::LockedExit,
+            60 => Self::RotatingTransporterUp,
+            61 => Self::RotatingTransporterRight,
+            62 => Self::RotatingTransporterDown,
+            63 => Self::RotatingTransporterLeft,
+            64 => Self::ReversingTransporterUp,
+            65 => Self::ReversingTransporterRight,
+            66 => Self::ReversingTransporterDown,
+            67 => Self::ReversingTransporterLeft,
+            68 => Self::LaserEmitterUp,
+            69 => Self::LaserEmitterRight,
+            70 => Self::LaserEmitterDown,
+            71 => Self::LaserEmitterLeft,
+            72 => Self::SlashMirror,
+            73 => Self::BackslashMirror,
+            74 => Self::Receiver,
+            75 => Self::Checkpoint,
+            76 => Self::Boulder,
             _ => return Err(()),
         };
         Ok(object_type)
@@ -255,10 +756,10 @@ impl ObjectSelectorBundle {
                     width: Val::Px(SELECTOR_WIDTH as f32),
                     height: Val::Px(SELECTOR_HEIGHT as f32),
                     grid_template_columns: (0..NUM_COLUMNS)
-                        .map(|_| GridTrack::px(GRID_SIZE as f32))
+                        .map(|_| GridTrack::px(SELECTOR_CELL_SIZE as f32))
                         .collect(),
                     grid_template_rows: (0..NUM_ROWS)
-                        .map(|_| GridTrack::px(GRID_SIZE as f32))
+                        .map(|_| GridTrack::px(SELECTOR_CELL_SIZE as f32))
                         .collect(),
                     row_gap: Val::Px(SELECTOR_OUTLINE_WIDTH as f32),
                     column_gap: Val::Px(SELECTOR_OUTLINE_WIDTH as f32),
@@ -277,6 +778,11 @@ impl ObjectSelectorBundle {
             let (texture, atlas) = object_type.get_texture(assets);
             let image = ImageBundle {
                 image: UiImage::new(texture),
+                style: Style {
+                    width: Val::Px(SELECTOR_CELL_SIZE as f32),
+                    height: Val::Px(SELECTOR_CELL_SIZE as f32),
+                    ..Default::default()
+                },
                 ..Default::default()
             };
             let interaction = Interaction::None;
@@ -289,3 +795,50 @@ impl ObjectSelectorBundle {
         }
     }
 }
+
+/// Translucent copy of the selected object on the square under the cursor.
+#[derive(Component)]
+pub struct PlacementPreview;
+
+#[derive(Bundle)]
+pub struct PlacementPreviewBundle {
+    preview: PlacementPreview,
+    sprite: SpriteBundle,
+}
+
+impl PlacementPreviewBundle {
+    pub fn spawn(
+        cb: &mut ChildBuilder,
+        object_type: EditorObjectType,
+        position: Position,
+        dimensions: &Dimensions,
+        assets: &GameObjectAssets,
+    ) {
+        let (texture, atlas) = object_type.get_texture(assets);
+        let bundle = Self {
+            preview: PlacementPreview,
+            sprite: SpriteBundle {
+                texture,
+                sprite: Sprite {
+                    color: TRANSLUCENT_WHITE,
+                    custom_size: Some(Vec2::new(GRID_SIZE as f32, GRID_SIZE as f32)),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(Vec3::new(
+                    (-(dimensions.width * HALF_GRID_SIZE) + position.x * GRID_SIZE - HALF_GRID_SIZE)
+                        as f32,
+                    ((dimensions.height * HALF_GRID_SIZE) - position.y * GRID_SIZE + HALF_GRID_SIZE)
+                        as f32,
+                    20.,
+                )),
+                ..Default::default()
+            },
+        };
+
+        if let Some(atlas) = atlas {
+            cb.spawn((bundle, atlas));
+        } else {
+            cb.spawn(bundle);
+        }
+    }
+}
@@ -1,8 +1,17 @@
-use bevy::prelude::*;
+use bevy::{prelude::*, window::PrimaryWindow};
 
-use crate::constants::*;
+use crate::{
+    constants::*,
+    game_object::{GameObjectAssets, Position},
+    level::Dimensions,
+    Background,
+};
 
-use super::{EditorObjectType, SelectedObjectType, SELECTOR_OUTLINE_WIDTH};
+use super::{
+    get_cursor_grid_position, is_cursor_over_ui, Editor, EditorObjectType, EditorTool,
+    PlacementPreview, PlacementPreviewBundle, SelectedObjectType, SelectedTool, VisibleLayers,
+    SELECTOR_OUTLINE_WIDTH,
+};
 
 pub fn on_object_selector_input(
     mut interaction_query: Query<
@@ -28,6 +37,52 @@ pub fn on_object_selector_input(
     }
 }
 
+/// Selects palette entries using their hotkeys.
+pub fn on_object_selector_keyboard_input(
+    mut selected_object_type: ResMut<SelectedObjectType>,
+    editor_query: Query<Entity, With<Editor>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    if editor_query.get_single().is_err()
+        || keys.any_pressed([
+            KeyCode::AltLeft,
+            KeyCode::AltRight,
+            KeyCode::ControlLeft,
+            KeyCode::ControlRight,
+        ])
+    {
+        return;
+    }
+
+    for key in keys.get_just_pressed() {
+        if let Some(object_type) = EditorObjectType::from_hotkey(*key) {
+            **selected_object_type = Some(object_type);
+        }
+    }
+}
+
+/// Turns the selected object with Space, or the other way around with
+/// Shift+Space.
+pub fn rotate_selected_object(
+    mut selected_object_type: ResMut<SelectedObjectType>,
+    editor_query: Query<Entity, With<Editor>>,
+    keys: Res<ButtonInput<KeyCode>>,
+) {
+    if editor_query.get_single().is_err() || !keys.just_pressed(KeyCode::Space) {
+        return;
+    }
+
+    let Some(object_type) = **selected_object_type else {
+        return;
+    };
+
+    let clockwise = !keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+    let rotated = object_type.rotate(clockwise);
+    if rotated != object_type {
+        **selected_object_type = Some(rotated);
+    }
+}
+
 pub fn on_selected_object_change(
     mut commands: Commands,
     mut query: Query<(Entity, &EditorObjectType, Option<&mut Outline>)>,
@@ -55,3 +110,58 @@ pub fn on_selected_object_change(
         }
     }
 }
+
+/// Shows the selected object on the square a click would place it on.
+#[allow(clippy::too_many_arguments)]
+pub fn update_placement_preview(
+    mut commands: Commands,
+    mut preview: Local<Option<(EditorObjectType, Position)>>,
+    preview_query: Query<Entity, With<PlacementPreview>>,
+    background_query: Query<(Entity, &Transform), With<Background>>,
+    editor_query: Query<Entity, With<Editor>>,
+    selected_object_type: Res<SelectedObjectType>,
+    selected_tool: Res<SelectedTool>,
+    visible_layers: Res<VisibleLayers>,
+    ui_query: Query<&Interaction>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    dimensions: Res<Dimensions>,
+    assets: Res<GameObjectAssets>,
+) {
+    let (background, transform) = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let new_preview = if editor_query.get_single().is_err()
+        || **selected_tool == EditorTool::Select
+        || is_cursor_over_ui(&ui_query)
+    {
+        None
+    } else {
+        let window = window_query
+            .get_single()
+            .expect("there should be only one window");
+
+        selected_object_type
+            .filter(|object_type| visible_layers.can_place(*object_type))
+            .zip(
+                get_cursor_grid_position(window, transform, &dimensions)
+                    .filter(|position| dimensions.contains(*position)),
+            )
+    };
+
+    if *preview == new_preview && !dimensions.is_changed() {
+        return;
+    }
+
+    for entity in &preview_query {
+        commands.entity(entity).despawn();
+    }
+
+    if let Some((object_type, position)) = new_preview {
+        commands.entity(background).with_children(|cb| {
+            PlacementPreviewBundle::spawn(cb, object_type, position, &dimensions, &assets);
+        });
+    }
+
+    *preview = new_preview;
+}
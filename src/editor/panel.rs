@@ -0,0 +1,33 @@
+use bevy::prelude::*;
+
+use crate::constants::*;
+
+/// Panel that floats over the board, laid out as a column.
+#[derive(Bundle)]
+pub struct PanelBundle {
+    background: NodeBundle,
+
+    /// Keeps clicks on the panel from going through to the board.
+    interaction: Interaction,
+}
+
+impl PanelBundle {
+    /// Creates a panel with the given style for its position and spacing.
+    pub fn new(style: Style, border_color: Color) -> Self {
+        Self {
+            background: NodeBundle {
+                style: Style {
+                    border: UiRect::all(Val::Px(2.)),
+                    flex_direction: FlexDirection::Column,
+                    position_type: PositionType::Absolute,
+                    ..style
+                },
+                background_color: GRAY_BACKGROUND.into(),
+                border_color: border_color.into(),
+                z_index: ZIndex::Global(100),
+                ..Default::default()
+            },
+            interaction: Interaction::None,
+        }
+    }
+}
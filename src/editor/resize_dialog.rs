@@ -0,0 +1,256 @@
+use bevy::prelude::*;
+
+use crate::{constants::*, fonts::Fonts, level::Dimensions, LevelObject};
+
+use super::{button::EditorButtonBundle, panel::PanelBundle, to_editor_object, EditorObject};
+
+/// Maximum number of cropped objects that are listed in the dialog.
+const MAX_LISTED_OBJECTS: usize = 8;
+
+/// Point of the level that stays in place when it is resized, given by its
+/// column and row from 0 (left or top) to 2 (right or bottom).
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Anchor {
+    pub column: i16,
+    pub row: i16,
+}
+
+/// Settings of the resize dialog while it is open.
+#[derive(Clone, Copy)]
+pub struct ResizeSettings {
+    pub dimensions: Dimensions,
+    pub anchor: Anchor,
+
+    /// Offset all objects are moved by, on top of the offset from the anchor.
+    pub shift: (i16, i16),
+}
+
+impl ResizeSettings {
+    pub fn new(dimensions: Dimensions) -> Self {
+        Self {
+            dimensions,
+            anchor: Anchor::default(),
+            shift: (0, 0),
+        }
+    }
+
+    /// Returns the offset objects are moved by when resizing a level with the
+    /// given dimensions.
+    pub fn offset(&self, current: &Dimensions) -> (i16, i16) {
+        let dx = (self.dimensions.width - current.width) * self.anchor.column / 2;
+        let dy = (self.dimensions.height - current.height) * self.anchor.row / 2;
+        (dx + self.shift.0, dy + self.shift.1)
+    }
+
+    /// Returns the object as it is after resizing, or `None` if it gets
+    /// cropped.
+    pub fn resize_object(
+        &self,
+        (object_type, mut object): EditorObject,
+        current: &Dimensions,
+    ) -> Option<EditorObject> {
+        let (dx, dy) = self.offset(current);
+        object.position.x += dx;
+        object.position.y += dy;
+        for waypoint in &mut object.waypoints {
+            waypoint.x += dx;
+            waypoint.y += dy;
+        }
+
+        self.dimensions
+            .contains(object.position)
+            .then_some((object_type, object))
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct ResizeDialog(pub Option<ResizeSettings>);
+
+#[derive(Clone, Component, Copy)]
+pub enum ResizeAction {
+    ChangeWidth(i16),
+    ChangeHeight(i16),
+    SetAnchor(Anchor),
+    Shift(i16, i16),
+    Apply,
+    Cancel,
+}
+
+#[derive(Component)]
+pub struct ResizeDialogPanel;
+
+#[derive(Bundle)]
+pub struct ResizeDialogBundle {
+    background: PanelBundle,
+    panel: ResizeDialogPanel,
+}
+
+impl ResizeDialogBundle {
+    pub fn new() -> Self {
+        Self {
+            background: PanelBundle::new(
+                Style {
+                    padding: UiRect::all(Val::Px(12.)),
+                    top: Val::Px(10.),
+                    right: Val::Px(EDITOR_WIDTH as f32 + 10.),
+                    row_gap: Val::Px(8.),
+                    ..Default::default()
+                },
+                RED,
+            ),
+            panel: ResizeDialogPanel,
+        }
+    }
+
+    pub fn populate<'a>(
+        cb: &mut ChildBuilder,
+        settings: &ResizeSettings,
+        current: &Dimensions,
+        objects: impl Iterator<Item = LevelObject<'a>>,
+        fonts: &Fonts,
+    ) {
+        let text_style = |color| TextStyle {
+            font: fonts.poppins_light.clone(),
+            font_size: 16.,
+            color,
+        };
+
+        cb.spawn(TextBundle::from_section("Resize level", text_style(WHITE)));
+
+        let (dx, dy) = settings.shift;
+        for (label, value, decrease, increase) in [
+            (
+                "Width:",
+                settings.dimensions.width,
+                ResizeAction::ChangeWidth(-1),
+                ResizeAction::ChangeWidth(1),
+            ),
+            (
+                "Height:",
+                settings.dimensions.height,
+                ResizeAction::ChangeHeight(-1),
+                ResizeAction::ChangeHeight(1),
+            ),
+            (
+                "Shift X:",
+                dx,
+                ResizeAction::Shift(-1, 0),
+                ResizeAction::Shift(1, 0),
+            ),
+            (
+                "Shift Y:",
+                dy,
+                ResizeAction::Shift(0, -1),
+                ResizeAction::Shift(0, 1),
+            ),
+        ] {
+            cb.spawn(row()).with_children(|cb| {
+                cb.spawn(
+                    TextBundle::from_section(label, text_style(WHITE)).with_style(Style {
+                        width: Val::Px(70.),
+                        ..Default::default()
+                    }),
+                );
+
+                cb.spawn(EditorButtonBundle::with_width(decrease, 30.))
+                    .with_children(|cb| EditorButtonBundle::populate(cb, "-", fonts));
+
+                cb.spawn(
+                    TextBundle::from_section(value.to_string(), text_style(WHITE)).with_style(
+                        Style {
+                            width: Val::Px(30.),
+                            justify_content: JustifyContent::Center,
+                            ..Default::default()
+                        },
+                    ),
+                );
+
+                cb.spawn(EditorButtonBundle::with_width(increase, 30.))
+                    .with_children(|cb| EditorButtonBundle::populate(cb, "+", fonts));
+            });
+        }
+
+        cb.spawn(row()).with_children(|cb| {
+            cb.spawn(
+                TextBundle::from_section("Anchor:", text_style(WHITE)).with_style(Style {
+                    width: Val::Px(70.),
+                    ..Default::default()
+                }),
+            );
+
+            cb.spawn(NodeBundle {
+                style: Style {
+                    display: Display::Grid,
+                    grid_template_columns: RepeatedGridTrack::px(3, 20.),
+                    grid_template_rows: RepeatedGridTrack::px(3, 20.),
+                    row_gap: Val::Px(2.),
+                    column_gap: Val::Px(2.),
+                    ..Default::default()
+                },
+                ..Default::default()
+            })
+            .with_children(|cb| {
+                for row in 0..3 {
+                    for column in 0..3 {
+                        let anchor = Anchor { column, row };
+                        cb.spawn((
+                            ResizeAction::SetAnchor(anchor),
+                            ButtonBundle {
+                                background_color: if anchor == settings.anchor {
+                                    RED.into()
+                                } else {
+                                    DARK_GRAY.into()
+                                },
+                                border_radius: BorderRadius::all(Val::Px(2.)),
+                                ..Default::default()
+                            },
+                        ));
+                    }
+                }
+            });
+        });
+
+        let cropped: Vec<_> = objects
+            .map(to_editor_object)
+            .filter(|object| settings.resize_object(object.clone(), current).is_none())
+            .collect();
+        if !cropped.is_empty() {
+            cb.spawn(TextBundle::from_section(
+                format!("{} objects will be cropped:", cropped.len()),
+                text_style(RED),
+            ));
+            for (object_type, object) in cropped.iter().take(MAX_LISTED_OBJECTS) {
+                cb.spawn(TextBundle::from_section(
+                    format!("{object_type} at {}", object.position),
+                    text_style(WHITE),
+                ));
+            }
+            if cropped.len() > MAX_LISTED_OBJECTS {
+                cb.spawn(TextBundle::from_section(
+                    format!("...and {} more", cropped.len() - MAX_LISTED_OBJECTS),
+                    text_style(LIGHT_GRAY),
+                ));
+            }
+        }
+
+        cb.spawn(row()).with_children(|cb| {
+            cb.spawn(EditorButtonBundle::new(ResizeAction::Apply))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Apply", fonts));
+
+            cb.spawn(EditorButtonBundle::new(ResizeAction::Cancel))
+                .with_children(|cb| EditorButtonBundle::populate(cb, "Cancel", fonts));
+        });
+    }
+}
+
+fn row() -> NodeBundle {
+    NodeBundle {
+        style: Style {
+            flex_direction: FlexDirection::Row,
+            align_items: AlignItems::Center,
+            column_gap: Val::Px(8.),
+            ..Default::default()
+        },
+        ..Default::default()
+    }
+}
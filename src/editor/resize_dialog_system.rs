@@ -0,0 +1,166 @@
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{GameObjectAssets, Position},
+    level::Dimensions,
+    Background, LevelObject,
+};
+
+use super::{
+    to_editor_object, Editor, EditorChange, EditorHistory, ResizeAction, ResizeDialog,
+    ResizeDialogBundle, ResizeDialogPanel, ResizeSettings,
+};
+
+/// Shows the resize dialog while it is open, and rebuilds it whenever its
+/// settings or the level change.
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_resize_dialog(
+    mut commands: Commands,
+    mut dialog: ResMut<ResizeDialog>,
+    panel_query: Query<Entity, With<ResizeDialogPanel>>,
+    editor_query: Query<Entity, With<Editor>>,
+    objects: Query<LevelObject>,
+    changed_objects: Query<(), Or<(Added<Position>, Changed<Position>)>>,
+    mut removed_objects: RemovedComponents<Position>,
+    dimensions: Res<Dimensions>,
+    fonts: Res<Fonts>,
+) {
+    if editor_query.get_single().is_err() && dialog.0.is_some() {
+        dialog.0 = None;
+    }
+
+    let objects_changed = !changed_objects.is_empty() || removed_objects.read().count() > 0;
+    let panel = panel_query.get_single().ok();
+    if panel.is_some() && !dialog.is_changed() && !dimensions.is_changed() && !objects_changed {
+        return;
+    }
+
+    if let Some(panel) = panel {
+        commands.entity(panel).despawn_recursive();
+    }
+
+    if let Some(settings) = &dialog.0 {
+        commands
+            .spawn(ResizeDialogBundle::new())
+            .with_children(|cb| {
+                ResizeDialogBundle::populate(cb, settings, &dimensions, objects.iter(), &fonts)
+            });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_resize_dialog_input(
+    mut commands: Commands,
+    mut interaction_query: Query<
+        (&Interaction, &ResizeAction, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut dialog: ResMut<ResizeDialog>,
+    mut dimensions: ResMut<Dimensions>,
+    mut history: ResMut<EditorHistory>,
+    background_query: Query<Entity, With<Background>>,
+    objects: Query<(Entity, LevelObject)>,
+    assets: Res<GameObjectAssets>,
+) {
+    for (interaction, action, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = WHITE.into();
+
+                let Some(settings) = &mut dialog.0 else {
+                    continue;
+                };
+
+                match *action {
+                    ResizeAction::ChangeWidth(delta) => {
+                        if settings.dimensions.width + delta > 0 {
+                            settings.dimensions.width += delta;
+                        }
+                    }
+                    ResizeAction::ChangeHeight(delta) => {
+                        if settings.dimensions.height + delta > 0 {
+                            settings.dimensions.height += delta;
+                        }
+                    }
+                    ResizeAction::SetAnchor(anchor) => {
+                        settings.anchor = anchor;
+                    }
+                    ResizeAction::Shift(dx, dy) => {
+                        settings.shift.0 += dx;
+                        settings.shift.1 += dy;
+                    }
+                    ResizeAction::Apply => {
+                        let background = background_query
+                            .get_single()
+                            .expect("there should be only one background");
+
+                        resize_level(
+                            &mut commands,
+                            settings,
+                            &mut dimensions,
+                            &mut history,
+                            background,
+                            &objects,
+                            &assets,
+                        );
+                        dialog.0 = None;
+                    }
+                    ResizeAction::Cancel => {
+                        dialog.0 = None;
+                    }
+                }
+            }
+            Interaction::Hovered => {
+                *color = LIGHT_GRAY.into();
+            }
+            Interaction::None => {
+                let is_selected_anchor = match *action {
+                    ResizeAction::SetAnchor(anchor) => {
+                        dialog.0.is_some_and(|settings| settings.anchor == anchor)
+                    }
+                    _ => false,
+                };
+                *color = if is_selected_anchor { RED } else { DARK_GRAY }.into();
+            }
+        }
+    }
+}
+
+/// Resizes the level with the given settings, as a single change that can be
+/// undone.
+pub(super) fn resize_level(
+    commands: &mut Commands,
+    settings: &ResizeSettings,
+    dimensions: &mut Dimensions,
+    history: &mut EditorHistory,
+    background: Entity,
+    objects: &Query<(Entity, LevelObject)>,
+    assets: &GameObjectAssets,
+) {
+    let mut change = EditorChange {
+        resized: Some((*dimensions, settings.dimensions)),
+        ..Default::default()
+    };
+    let moves = settings.offset(dimensions) != (0, 0);
+    for (_, object) in objects {
+        let object = to_editor_object(object);
+        let resized = settings.resize_object(object.clone(), dimensions);
+        if moves || resized.is_none() {
+            change.removed.push(object);
+            change.added.extend(resized);
+        }
+    }
+
+    change.clone().apply(
+        commands,
+        background,
+        objects
+            .iter()
+            .map(|(entity, (object_type, position, ..))| (entity, *object_type, *position)),
+        dimensions,
+        assets,
+    );
+    history.push(change);
+}
@@ -0,0 +1,247 @@
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    game_object::{Direction, ObjectType, Position},
+    level::Dimensions,
+    LevelObject,
+};
+
+use super::{to_editor_object, EditorObject};
+
+/// Rectangular region of the board, given by its top-left and bottom-right
+/// corners.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Area {
+    pub top_left: Position,
+    pub bottom_right: Position,
+}
+
+impl Area {
+    /// Returns the area spanned by two opposite corners in any order.
+    pub fn between(a: Position, b: Position) -> Self {
+        Self {
+            top_left: Position {
+                x: a.x.min(b.x),
+                y: a.y.min(b.y),
+            },
+            bottom_right: Position {
+                x: a.x.max(b.x),
+                y: a.y.max(b.y),
+            },
+        }
+    }
+
+    /// Returns the area of the given size with its top-left corner at the
+    /// given position.
+    pub fn with_size(top_left: Position, width: i16, height: i16) -> Self {
+        Self {
+            top_left,
+            bottom_right: Position {
+                x: top_left.x + width - 1,
+                y: top_left.y + height - 1,
+            },
+        }
+    }
+
+    pub fn contains(&self, position: Position) -> bool {
+        position.x >= self.top_left.x
+            && position.x <= self.bottom_right.x
+            && position.y >= self.top_left.y
+            && position.y <= self.bottom_right.y
+    }
+
+    pub fn width(&self) -> i16 {
+        self.bottom_right.x - self.top_left.x + 1
+    }
+
+    pub fn height(&self) -> i16 {
+        self.bottom_right.y - self.top_left.y + 1
+    }
+
+    pub fn translate(&self, (dx, dy): (i16, i16)) -> Self {
+        Self::with_size(
+            Position {
+                x: self.top_left.x + dx,
+                y: self.top_left.y + dy,
+            },
+            self.width(),
+            self.height(),
+        )
+    }
+}
+
+/// The region of the board that is selected with [EditorTool::Select].
+///
+/// [EditorTool::Select]: super::EditorTool::Select
+#[derive(Default, Resource)]
+pub struct Selection {
+    pub area: Option<Area>,
+
+    /// Offset the selection is being dragged by, while it is being moved.
+    pub drag_offset: (i16, i16),
+}
+
+/// Objects that were copied from the board, kept across levels.
+#[derive(Default, Resource)]
+pub struct Clipboard(pub Option<Region>);
+
+/// The objects within an area of the board, with positions relative to the
+/// area's top-left corner.
+#[derive(Clone)]
+pub struct Region {
+    pub width: i16,
+    pub height: i16,
+    pub objects: Vec<EditorObject>,
+}
+
+impl Region {
+    /// Copies the objects within the given area.
+    pub fn copy<'a>(area: Area, objects: impl Iterator<Item = LevelObject<'a>>) -> Self {
+        let to_relative = |position: Position| Position {
+            x: position.x - area.top_left.x,
+            y: position.y - area.top_left.y,
+        };
+
+        let objects = objects
+            .filter(|(_, position, ..)| area.contains(**position))
+            .map(|object| {
+                let (object_type, mut object) = to_editor_object(object);
+                object.position = to_relative(object.position);
+                for waypoint in &mut object.waypoints {
+                    *waypoint = to_relative(*waypoint);
+                }
+                (object_type, object)
+            })
+            .collect();
+
+        Self {
+            width: area.width(),
+            height: area.height(),
+            objects,
+        }
+    }
+
+    /// Returns the objects as they would be placed with the region's top-left
+    /// corner at the given position.
+    pub fn objects_at(&self, top_left: Position) -> impl Iterator<Item = EditorObject> + '_ {
+        let to_absolute = move |position: Position| Position {
+            x: position.x + top_left.x,
+            y: position.y + top_left.y,
+        };
+
+        self.objects
+            .iter()
+            .cloned()
+            .map(move |(object_type, mut object)| {
+                object.position = to_absolute(object.position);
+                for waypoint in &mut object.waypoints {
+                    *waypoint = to_absolute(*waypoint);
+                }
+                (object_type, object)
+            })
+    }
+
+    /// Rotates the region a quarter turn clockwise.
+    pub fn rotate_clockwise(&mut self) {
+        let height = self.height;
+        self.transform(
+            |position| Position {
+                x: height - 1 - position.y,
+                y: position.x,
+            },
+            Direction::right_hand,
+        );
+        (self.width, self.height) = (self.height, self.width);
+    }
+
+    /// Mirrors the region from left to right.
+    pub fn mirror_horizontally(&mut self) {
+        let width = self.width;
+        self.transform(
+            |position| Position {
+                x: width - 1 - position.x,
+                y: position.y,
+            },
+            |direction| match direction {
+                Direction::Left | Direction::Right => direction.inverse(),
+                Direction::Up | Direction::Down => direction,
+            },
+        );
+    }
+
+    /// Mirrors the region from top to bottom.
+    pub fn mirror_vertically(&mut self) {
+        let height = self.height;
+        self.transform(
+            |position| Position {
+                x: position.x,
+                y: height - 1 - position.y,
+            },
+            |direction| match direction {
+                Direction::Up | Direction::Down => direction.inverse(),
+                Direction::Left | Direction::Right => direction,
+            },
+        );
+    }
+
+    fn transform(
+        &mut self,
+        transform_position: impl Fn(Position) -> Position,
+        transform_direction: impl Fn(Direction) -> Direction,
+    ) {
+        for (object_type, object) in &mut self.objects {
+            // Rotating or mirroring a diagonal mirror turns it the other way.
+            *object_type = match *object_type {
+                ObjectType::SlashMirror => ObjectType::BackslashMirror,
+                ObjectType::BackslashMirror => ObjectType::SlashMirror,
+                object_type => object_type,
+            };
+
+            object.position = transform_position(object.position);
+            object.direction = object.direction.map(&transform_direction);
+            for waypoint in &mut object.waypoints {
+                *waypoint = transform_position(*waypoint);
+            }
+        }
+    }
+}
+
+#[derive(Component)]
+pub struct SelectionHighlight;
+
+/// Translucent overlay that marks an area of the board.
+#[derive(Bundle)]
+pub struct AreaHighlightBundle {
+    sprite: SpriteBundle,
+}
+
+impl AreaHighlightBundle {
+    pub fn spawn(area: Area, color: Color, dimensions: &Dimensions) -> Self {
+        let (left, top) = (area.top_left.x - 1, area.top_left.y - 1);
+        let (width, height) = (area.width(), area.height());
+
+        Self {
+            sprite: SpriteBundle {
+                sprite: Sprite {
+                    color,
+                    custom_size: Some(Vec2::new(
+                        (width * GRID_SIZE) as f32,
+                        (height * GRID_SIZE) as f32,
+                    )),
+                    ..Default::default()
+                },
+                transform: Transform::from_translation(Vec3::new(
+                    (-(dimensions.width * HALF_GRID_SIZE)
+                        + left * GRID_SIZE
+                        + width * HALF_GRID_SIZE) as f32,
+                    ((dimensions.height * HALF_GRID_SIZE)
+                        - top * GRID_SIZE
+                        - height * HALF_GRID_SIZE) as f32,
+                    10.,
+                )),
+                ..Default::default()
+            },
+        }
+    }
+}
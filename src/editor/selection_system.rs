@@ -0,0 +1,330 @@
+use std::collections::BTreeSet;
+
+use bevy::{prelude::*, window::PrimaryWindow};
+
+use crate::{
+    constants::*,
+    game_object::{GameObjectAssets, Position},
+    level::Dimensions,
+    spawn_level_objects, Background, LevelObject,
+};
+
+use super::{
+    get_cursor_grid_position, is_alt_pressed, is_cursor_over_ui, to_editor_object, Area,
+    AreaHighlightBundle, Clipboard, Editor, EditorChange, EditorHistory, EditorTool, Region,
+    SelectedTool, Selection, SelectionHighlight, VisibleLayers, WaypointEditing,
+};
+
+/// Dragging with [EditorTool::Select] that is in progress.
+pub enum SelectionDrag {
+    Selecting { start: Position },
+    Moving { start: Position },
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn select_area(
+    mut commands: Commands,
+    mut drag: Local<Option<SelectionDrag>>,
+    mut history: ResMut<EditorHistory>,
+    mut selection: ResMut<Selection>,
+    background_query: Query<(Entity, &Transform), With<Background>>,
+    editor_query: Query<Entity, With<Editor>>,
+    objects: Query<(Entity, LevelObject)>,
+    selected_tool: Res<SelectedTool>,
+    visible_layers: Res<VisibleLayers>,
+    waypoint_editing: Res<WaypointEditing>,
+    buttons: Res<ButtonInput<MouseButton>>,
+    keys: Res<ButtonInput<KeyCode>>,
+    ui_query: Query<&Interaction>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    dimensions: Res<Dimensions>,
+    assets: Res<GameObjectAssets>,
+) {
+    if editor_query.get_single().is_err() || **selected_tool != EditorTool::Select {
+        if selection.area.is_some() {
+            *selection = Selection::default();
+        }
+        *drag = None;
+        return;
+    }
+
+    let window = window_query
+        .get_single()
+        .expect("there should be only one window");
+
+    let (background, transform) = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let position = get_cursor_grid_position(window, transform, &dimensions);
+
+    // Clicks add waypoints instead while they are being edited.
+    if buttons.just_pressed(MouseButton::Left)
+        && !is_alt_pressed(&keys)
+        && !is_cursor_over_ui(&ui_query)
+        && waypoint_editing.0.is_none()
+    {
+        *drag = position.map(|position| {
+            if selection.area.is_some_and(|area| area.contains(position)) {
+                SelectionDrag::Moving { start: position }
+            } else {
+                SelectionDrag::Selecting { start: position }
+            }
+        });
+    }
+
+    let Some(current_drag) = drag.as_ref() else {
+        return;
+    };
+
+    if let Some(position) = position {
+        match current_drag {
+            SelectionDrag::Selecting { start } => {
+                let area = Area::between(*start, position);
+                if selection.area != Some(area) {
+                    selection.area = Some(area);
+                }
+            }
+            SelectionDrag::Moving { start } => {
+                let offset = (position.x - start.x, position.y - start.y);
+                if selection.drag_offset != offset {
+                    selection.drag_offset = offset;
+                }
+            }
+        }
+    }
+
+    if buttons.pressed(MouseButton::Left) {
+        return;
+    }
+
+    if let (Some(SelectionDrag::Moving { .. }), Some(area)) = (drag.take(), selection.area) {
+        if selection.drag_offset != (0, 0) {
+            let mut change = EditorChange::default();
+            let mut removed = BTreeSet::new();
+            let objects = editable_objects(&objects, &visible_layers);
+            let region = Region::copy(area, objects.iter().map(|(_, object)| *object));
+            clear_area(&mut commands, area, &objects, &mut removed, &mut change);
+
+            let new_area = area.translate(selection.drag_offset);
+            place_region(
+                &mut commands,
+                background,
+                &region,
+                new_area.top_left,
+                &objects,
+                &mut removed,
+                &dimensions,
+                &assets,
+                &mut change,
+            );
+
+            history.push(change);
+            selection.area = Some(new_area);
+        }
+        selection.drag_offset = (0, 0);
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn on_selection_keyboard_input(
+    mut commands: Commands,
+    mut clipboard: ResMut<Clipboard>,
+    mut history: ResMut<EditorHistory>,
+    mut selection: ResMut<Selection>,
+    background_query: Query<(Entity, &Transform), With<Background>>,
+    objects: Query<(Entity, LevelObject)>,
+    keys: Res<ButtonInput<KeyCode>>,
+    window_query: Query<&Window, With<PrimaryWindow>>,
+    visible_layers: Res<VisibleLayers>,
+    dimensions: Res<Dimensions>,
+    assets: Res<GameObjectAssets>,
+) {
+    let Some(area) = selection.area else {
+        return;
+    };
+
+    let (background, transform) = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let ctrl_pressed = keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]);
+    let shift_pressed = keys.any_pressed([KeyCode::ShiftLeft, KeyCode::ShiftRight]);
+
+    let mut change = EditorChange::default();
+    let mut removed = BTreeSet::new();
+    if !keys.any_just_pressed([
+        KeyCode::Backspace,
+        KeyCode::Delete,
+        KeyCode::KeyC,
+        KeyCode::KeyM,
+        KeyCode::KeyR,
+        KeyCode::KeyV,
+        KeyCode::KeyX,
+    ]) {
+        return;
+    }
+
+    let objects = editable_objects(&objects, &visible_layers);
+    let copy = || Region::copy(area, objects.iter().map(|(_, object)| *object));
+
+    if keys.any_just_pressed([KeyCode::Delete, KeyCode::Backspace]) {
+        clear_area(&mut commands, area, &objects, &mut removed, &mut change);
+    } else if !ctrl_pressed {
+        return;
+    } else if keys.just_pressed(KeyCode::KeyC) {
+        clipboard.0 = Some(copy());
+    } else if keys.just_pressed(KeyCode::KeyX) {
+        clipboard.0 = Some(copy());
+        clear_area(&mut commands, area, &objects, &mut removed, &mut change);
+    } else if keys.just_pressed(KeyCode::KeyV) {
+        let Some(region) = clipboard.0.as_ref() else {
+            return;
+        };
+
+        // Paste at the cursor if it points at the board, or over the
+        // selection otherwise.
+        let window = window_query
+            .get_single()
+            .expect("there should be only one window");
+        let top_left = get_cursor_grid_position(window, transform, &dimensions)
+            .filter(|position| dimensions.contains(*position))
+            .unwrap_or(area.top_left);
+
+        place_region(
+            &mut commands,
+            background,
+            region,
+            top_left,
+            &objects,
+            &mut removed,
+            &dimensions,
+            &assets,
+            &mut change,
+        );
+        selection.area = Some(Area::with_size(top_left, region.width, region.height));
+    } else if keys.any_just_pressed([KeyCode::KeyR, KeyCode::KeyM]) {
+        let mut region = copy();
+        if keys.just_pressed(KeyCode::KeyR) {
+            region.rotate_clockwise();
+        } else if shift_pressed {
+            region.mirror_vertically();
+        } else {
+            region.mirror_horizontally();
+        }
+
+        clear_area(&mut commands, area, &objects, &mut removed, &mut change);
+        place_region(
+            &mut commands,
+            background,
+            &region,
+            area.top_left,
+            &objects,
+            &mut removed,
+            &dimensions,
+            &assets,
+            &mut change,
+        );
+        selection.area = Some(Area::with_size(area.top_left, region.width, region.height));
+    }
+
+    history.push(change);
+}
+
+pub fn update_selection_highlight(
+    mut commands: Commands,
+    background_query: Query<Entity, With<Background>>,
+    highlight_query: Query<Entity, With<SelectionHighlight>>,
+    selection: Res<Selection>,
+    dimensions: Res<Dimensions>,
+) {
+    if !selection.is_changed() && !dimensions.is_changed() {
+        return;
+    }
+
+    for highlight in &highlight_query {
+        commands.entity(highlight).despawn();
+    }
+
+    if let Some(area) = selection.area {
+        let background = background_query
+            .get_single()
+            .expect("there should be only one background");
+
+        commands.entity(background).with_children(|cb| {
+            cb.spawn((
+                SelectionHighlight,
+                AreaHighlightBundle::spawn(
+                    area.translate(selection.drag_offset),
+                    SELECTION_BLUE,
+                    &dimensions,
+                ),
+            ));
+        });
+    }
+}
+
+/// Returns the objects on the visible layers, which are the only ones that
+/// selections affect.
+fn editable_objects<'a>(
+    objects: &'a Query<(Entity, LevelObject)>,
+    visible_layers: &VisibleLayers,
+) -> Vec<(Entity, LevelObject<'a>)> {
+    objects
+        .iter()
+        .filter(|(_, (object_type, ..))| visible_layers.is_editable(Some(object_type)))
+        .collect()
+}
+
+/// Removes all objects within the given area, except those that were already
+/// removed.
+fn clear_area(
+    commands: &mut Commands,
+    area: Area,
+    objects: &[(Entity, LevelObject)],
+    removed: &mut BTreeSet<Entity>,
+    change: &mut EditorChange,
+) {
+    for (entity, object) in objects {
+        if area.contains(*object.1) && removed.insert(*entity) {
+            commands.entity(*entity).despawn_recursive();
+            change.removed.push(to_editor_object(*object));
+        }
+    }
+}
+
+/// Replaces everything within the area covered by the region with the objects
+/// in the region.
+#[allow(clippy::too_many_arguments)]
+fn place_region(
+    commands: &mut Commands,
+    background: Entity,
+    region: &Region,
+    top_left: Position,
+    objects: &[(Entity, LevelObject)],
+    removed: &mut BTreeSet<Entity>,
+    dimensions: &Dimensions,
+    assets: &GameObjectAssets,
+    change: &mut EditorChange,
+) {
+    let area = Area::with_size(top_left, region.width, region.height);
+    clear_area(commands, area, objects, removed, change);
+
+    let added: Vec<_> = region
+        .objects_at(top_left)
+        .filter(|(_, object)| dimensions.contains(object.position))
+        .collect();
+
+    commands.entity(background).with_children(|cb| {
+        spawn_level_objects(
+            cb,
+            added
+                .iter()
+                .cloned()
+                .map(|(object_type, object)| (object_type, vec![object])),
+            assets,
+        );
+    });
+
+    change.added.extend(added);
+}
@@ -0,0 +1,136 @@
+use std::ops::{Deref, DerefMut};
+
+use bevy::prelude::*;
+
+use crate::{constants::*, fonts::Fonts};
+
+pub const TOOL_OUTLINE_WIDTH: f32 = 1.;
+
+/// Decides how the selected object is placed when clicking on the board.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, PartialEq)]
+pub enum EditorTool {
+    /// Places the object on every square the mouse is dragged over.
+    #[default]
+    Paint,
+
+    /// Places the object on a line from where the mouse is pressed to where it
+    /// is released.
+    Line,
+
+    /// Fills the rectangle between where the mouse is pressed and where it is
+    /// released.
+    Rectangle,
+
+    /// Fills the contiguous region of squares with the same contents as the
+    /// square that is clicked.
+    Fill,
+
+    /// Selects a rectangular area, which can be moved by dragging it, and be
+    /// copied, cut, pasted, rotated and mirrored using the keyboard.
+    Select,
+}
+
+impl EditorTool {
+    const ALL: [Self; 5] = [
+        Self::Paint,
+        Self::Line,
+        Self::Rectangle,
+        Self::Fill,
+        Self::Select,
+    ];
+
+    fn label(self) -> &'static str {
+        match self {
+            Self::Paint => "Paint",
+            Self::Line => "Line",
+            Self::Rectangle => "Rect",
+            Self::Fill => "Fill",
+            Self::Select => "Select",
+        }
+    }
+}
+
+#[derive(Default, Resource)]
+pub struct SelectedTool(EditorTool);
+
+impl Deref for SelectedTool {
+    type Target = EditorTool;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for SelectedTool {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Bundle)]
+pub struct ToolSelectorBundle {
+    node: NodeBundle,
+}
+
+impl ToolSelectorBundle {
+    pub fn new() -> Self {
+        Self {
+            node: NodeBundle {
+                style: Style {
+                    width: Val::Percent(100.),
+                    height: Val::Px(26.),
+                    flex_direction: FlexDirection::Row,
+                    align_items: AlignItems::Center,
+                    justify_content: JustifyContent::SpaceBetween,
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+        }
+    }
+
+    pub fn populate(cb: &mut ChildBuilder, selected_tool: EditorTool, fonts: &Fonts) {
+        for tool in EditorTool::ALL {
+            cb.spawn((
+                tool,
+                ButtonBundle {
+                    background_color: NORMAL_GRAY.into(),
+                    border_radius: BorderRadius::all(Val::Px(4.)),
+                    style: Style {
+                        height: Val::Px(26.),
+                        width: Val::Px(40.),
+                        align_content: AlignContent::Center,
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+                Outline::new(
+                    Val::Px(TOOL_OUTLINE_WIDTH),
+                    Val::ZERO,
+                    if tool == selected_tool {
+                        RED
+                    } else {
+                        Color::NONE
+                    },
+                ),
+            ))
+            .with_children(|cb| {
+                cb.spawn(TextBundle {
+                    text: Text::from_section(
+                        tool.label(),
+                        TextStyle {
+                            font: fonts.poppins_light.clone(),
+                            font_size: 13.,
+                            color: WHITE,
+                        },
+                    ),
+                    style: Style {
+                        margin: UiRect::all(Val::Auto),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                });
+            });
+        }
+    }
+}
@@ -0,0 +1,46 @@
+use bevy::prelude::*;
+
+use crate::constants::*;
+
+use super::{EditorTool, SelectedTool};
+
+pub fn on_tool_selector_input(
+    mut interaction_query: Query<
+        (&Interaction, &EditorTool, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut selected_tool: ResMut<SelectedTool>,
+) {
+    for (interaction, tool, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                *color = WHITE.into();
+
+                **selected_tool = *tool;
+            }
+            Interaction::Hovered => {
+                *color = LIGHT_GRAY.into();
+            }
+            Interaction::None => {
+                *color = NORMAL_GRAY.into();
+            }
+        }
+    }
+}
+
+pub fn on_selected_tool_change(
+    mut query: Query<(&EditorTool, &mut Outline)>,
+    selected_tool: Res<SelectedTool>,
+) {
+    if !selected_tool.is_changed() {
+        return;
+    }
+
+    for (tool, mut outline) in &mut query {
+        outline.color = if *tool == **selected_tool {
+            RED
+        } else {
+            Color::NONE
+        };
+    }
+}
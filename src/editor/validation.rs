@@ -0,0 +1,188 @@
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{Channel, ObjectType, Position},
+};
+
+use super::panel::PanelBundle;
+
+/// Maximum number of issues that are listed in the panel.
+const MAX_LISTED_ISSUES: usize = 10;
+
+/// Problem with the level that is being edited.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum LevelIssue {
+    NoPlayer,
+    NoExit,
+
+    /// None of the exits can be reached by any of the players. The position is
+    /// that of the first exit.
+    ExitUnreachable(Position),
+
+    /// A gate or switchable object while there is nothing on its channel to
+    /// trigger it.
+    NoTrigger(ObjectType, Position, Channel),
+
+    /// A trigger while there is nothing on its channel it can open or switch.
+    NothingToTrigger(ObjectType, Position, Channel),
+
+    /// An object that is outside the level's dimensions, which gets dropped
+    /// when the level is saved.
+    OutsideDimensions(ObjectType, Position),
+}
+
+impl LevelIssue {
+    /// Returns the position of the square the issue is about, if any.
+    pub fn position(&self) -> Option<Position> {
+        match self {
+            Self::NoPlayer | Self::NoExit => None,
+            Self::ExitUnreachable(position)
+            | Self::NoTrigger(_, position, _)
+            | Self::NothingToTrigger(_, position, _)
+            | Self::OutsideDimensions(_, position) => Some(*position),
+        }
+    }
+
+    /// Returns whether the issue prevents the level from being saved.
+    pub fn blocks_saving(&self) -> bool {
+        matches!(self, Self::NoPlayer)
+    }
+}
+
+impl std::fmt::Display for LevelIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NoPlayer => f.write_str("The level has no player"),
+            Self::NoExit => f.write_str("The level has no exit"),
+            Self::ExitUnreachable(_) => f.write_str("No exit can be reached by a player"),
+            Self::NoTrigger(object_type, position, Channel(channel)) => {
+                write!(
+                    f,
+                    "{object_type} at {position} has no trigger on channel {channel}"
+                )
+            }
+            Self::NothingToTrigger(object_type, position, Channel(channel)) => {
+                write!(
+                    f,
+                    "{object_type} at {position} has nothing to trigger on channel {channel}"
+                )
+            }
+            Self::OutsideDimensions(object_type, position) => {
+                write!(f, "{object_type} at {position} is outside the level")
+            }
+        }
+    }
+}
+
+/// The issues with the level that is being edited, which are kept up-to-date
+/// while the editor is open.
+#[derive(Default, Resource)]
+pub struct LevelValidation {
+    pub issues: Vec<LevelIssue>,
+
+    /// Whether the last attempt to save the level was refused because of one
+    /// of the issues.
+    pub save_refused: bool,
+
+    /// Square of the issue that was clicked in the panel.
+    pub highlighted: Option<Position>,
+}
+
+#[derive(Component)]
+pub struct ValidationPanel;
+
+/// Entry in the validation panel for the issue about the given square.
+#[derive(Component)]
+pub struct IssueEntry(pub Option<Position>);
+
+#[derive(Component)]
+pub struct IssueHighlight;
+
+#[derive(Bundle)]
+pub struct ValidationPanelBundle {
+    background: PanelBundle,
+    panel: ValidationPanel,
+}
+
+impl ValidationPanelBundle {
+    pub fn new(validation: &LevelValidation) -> Self {
+        let is_empty = validation.issues.is_empty() && !validation.save_refused;
+
+        Self {
+            background: PanelBundle::new(
+                Style {
+                    display: if is_empty {
+                        Display::None
+                    } else {
+                        Display::Flex
+                    },
+                    max_width: Val::Px(320.),
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                    bottom: Val::Px(10.),
+                    left: Val::Px(10.),
+                    ..Default::default()
+                },
+                RED,
+            ),
+            panel: ValidationPanel,
+        }
+    }
+
+    pub fn populate(cb: &mut ChildBuilder, validation: &LevelValidation, fonts: &Fonts) {
+        let text_style = |color| TextStyle {
+            font: fonts.poppins_light.clone(),
+            font_size: 14.,
+            color,
+        };
+
+        if validation.save_refused {
+            let reasons: Vec<_> = validation
+                .issues
+                .iter()
+                .filter(|issue| issue.blocks_saving())
+                .map(ToString::to_string)
+                .collect();
+            cb.spawn(TextBundle::from_section(
+                format!("Not saved: {}", reasons.join(", ")),
+                text_style(RED),
+            ));
+        }
+
+        for issue in validation.issues.iter().take(MAX_LISTED_ISSUES) {
+            let position = issue.position();
+            cb.spawn((
+                IssueEntry(position),
+                ButtonBundle {
+                    background_color: Color::NONE.into(),
+                    border_radius: BorderRadius::all(Val::Px(4.)),
+                    style: Style {
+                        padding: UiRect::axes(Val::Px(4.), Val::Px(2.)),
+                        ..Default::default()
+                    },
+                    ..Default::default()
+                },
+            ))
+            .with_children(|cb| {
+                let color = if position.is_some() && position == validation.highlighted {
+                    RED
+                } else {
+                    WHITE
+                };
+                cb.spawn(TextBundle::from_section(
+                    issue.to_string(),
+                    text_style(color),
+                ));
+            });
+        }
+
+        let num_unlisted = validation.issues.len().saturating_sub(MAX_LISTED_ISSUES);
+        if num_unlisted > 0 {
+            cb.spawn(TextBundle::from_section(
+                format!("...and {num_unlisted} more"),
+                text_style(LIGHT_GRAY),
+            ));
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::{BTreeSet, VecDeque};
+
+use bevy::prelude::*;
+
+use crate::{
+    constants::*,
+    fonts::Fonts,
+    game_object::{
+        Channel, Destructible, Direction, Massive, ObjectType, Openable, Position, Pushable,
+        Switchable, Trigger,
+    },
+    level::Dimensions,
+    Background,
+};
+
+use super::{
+    Area, AreaHighlightBundle, Editor, IssueEntry, IssueHighlight, LevelIssue, LevelValidation,
+    ValidationPanel, ValidationPanelBundle,
+};
+
+#[allow(clippy::type_complexity)]
+pub fn validate_level(
+    mut validation: ResMut<LevelValidation>,
+    editor_query: Query<Entity, With<Editor>>,
+    objects_query: Query<(
+        &ObjectType,
+        &Position,
+        Has<Massive>,
+        Has<Pushable>,
+        Has<Openable>,
+        Has<Destructible>,
+        Has<Trigger>,
+        Has<Switchable>,
+        Option<&Channel>,
+    )>,
+    dimensions: Res<Dimensions>,
+) {
+    if editor_query.get_single().is_err() {
+        if validation.highlighted.is_some() {
+            validation.highlighted = None;
+        }
+        return;
+    }
+
+    let mut players = Vec::new();
+    let mut exits = Vec::new();
+    let mut walls = BTreeSet::new();
+    let mut triggered = Vec::new();
+    let mut triggers = Vec::new();
+    let mut outside = Vec::new();
+    for (
+        object_type,
+        position,
+        is_massive,
+        is_pushable,
+        is_openable,
+        is_destructible,
+        is_trigger,
+        is_switchable,
+        channel,
+    ) in &objects_query
+    {
+        let channel = channel.copied().unwrap_or_default();
+        if !dimensions.contains(*position) {
+            outside.push(LevelIssue::OutsideDimensions(*object_type, *position));
+            continue;
+        }
+
+        match object_type {
+            ObjectType::Player => players.push(*position),
+            ObjectType::Exit | ObjectType::LockedExit => exits.push(*position),
+            _ if is_massive && !is_pushable && !is_openable && !is_destructible => {
+                walls.insert(*position);
+            }
+            _ => {}
+        }
+
+        if is_trigger {
+            triggers.push((*object_type, *position, channel));
+        }
+        if is_openable || is_switchable {
+            triggered.push((*object_type, *position, channel));
+        }
+    }
+
+    let mut issues = Vec::new();
+    if players.is_empty() {
+        issues.push(LevelIssue::NoPlayer);
+    }
+    exits.sort_unstable_by_key(|position| (position.y, position.x));
+    match exits.first() {
+        None => issues.push(LevelIssue::NoExit),
+        Some(exit)
+            if !players.is_empty() && !is_any_reachable(&players, &exits, &walls, &dimensions) =>
+        {
+            issues.push(LevelIssue::ExitUnreachable(*exit))
+        }
+        Some(_) => {}
+    }
+    let has_channel = |objects: &[(ObjectType, Position, Channel)], channel| {
+        objects
+            .iter()
+            .any(|(_, _, object_channel)| *object_channel == channel)
+    };
+    issues.extend(
+        triggered
+            .iter()
+            .filter(|(.., channel)| !has_channel(&triggers, *channel))
+            .map(|(object_type, position, channel)| {
+                LevelIssue::NoTrigger(*object_type, *position, *channel)
+            }),
+    );
+    issues.extend(
+        triggers
+            .iter()
+            .filter(|(.., channel)| !has_channel(&triggered, *channel))
+            .map(|(object_type, position, channel)| {
+                LevelIssue::NothingToTrigger(*object_type, *position, *channel)
+            }),
+    );
+    issues.extend(outside);
+
+    if validation.issues != issues {
+        if !issues.iter().any(LevelIssue::blocks_saving) {
+            validation.save_refused = false;
+        }
+        if validation.highlighted.is_some()
+            && !issues
+                .iter()
+                .any(|issue| issue.position() == validation.highlighted)
+        {
+            validation.highlighted = None;
+        }
+        validation.issues = issues;
+    }
+}
+
+/// Returns whether any of the targets can be reached from any of the starting
+/// positions, without passing walls that cannot be pushed, opened or blown up.
+fn is_any_reachable(
+    starts: &[Position],
+    targets: &[Position],
+    walls: &BTreeSet<Position>,
+    dimensions: &Dimensions,
+) -> bool {
+    let mut visited: BTreeSet<Position> = starts.iter().copied().collect();
+    let mut queue: VecDeque<Position> = starts.iter().copied().collect();
+    while let Some(position) = queue.pop_front() {
+        if targets.contains(&position) {
+            return true;
+        }
+
+        for direction in [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ] {
+            let (dx, dy) = direction.to_delta();
+            let neighbour = Position {
+                x: position.x + dx,
+                y: position.y + dy,
+            };
+            if dimensions.contains(neighbour)
+                && !walls.contains(&neighbour)
+                && visited.insert(neighbour)
+            {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    false
+}
+
+/// Shows the validation panel next to the editor, and rebuilds it whenever
+/// the issues change.
+pub fn update_validation_panel(
+    mut commands: Commands,
+    panel_query: Query<Entity, With<ValidationPanel>>,
+    editor_query: Query<Entity, With<Editor>>,
+    validation: Res<LevelValidation>,
+    fonts: Res<Fonts>,
+) {
+    let panel = panel_query.get_single().ok();
+    if editor_query.get_single().is_err() {
+        if let Some(panel) = panel {
+            commands.entity(panel).despawn_recursive();
+        }
+        return;
+    }
+
+    if panel.is_some() && !validation.is_changed() {
+        return;
+    }
+
+    if let Some(panel) = panel {
+        commands.entity(panel).despawn_recursive();
+    }
+
+    commands
+        .spawn(ValidationPanelBundle::new(&validation))
+        .with_children(|cb| ValidationPanelBundle::populate(cb, &validation, &fonts));
+}
+
+pub fn on_issue_interaction(
+    mut interaction_query: Query<
+        (&Interaction, &IssueEntry, &mut BackgroundColor),
+        Changed<Interaction>,
+    >,
+    mut validation: ResMut<LevelValidation>,
+) {
+    for (interaction, entry, mut color) in &mut interaction_query {
+        match *interaction {
+            Interaction::Pressed => {
+                if entry.0.is_some() && validation.highlighted != entry.0 {
+                    validation.highlighted = entry.0;
+                }
+            }
+            Interaction::Hovered => {
+                *color = LIGHT_GRAY.into();
+            }
+            Interaction::None => {
+                *color = Color::NONE.into();
+            }
+        }
+    }
+}
+
+pub fn update_issue_highlight(
+    mut commands: Commands,
+    background_query: Query<Entity, With<Background>>,
+    highlight_query: Query<Entity, With<IssueHighlight>>,
+    validation: Res<LevelValidation>,
+    dimensions: Res<Dimensions>,
+) {
+    if !validation.is_changed() && !dimensions.is_changed() {
+        return;
+    }
+
+    for highlight in &highlight_query {
+        commands.entity(highlight).despawn();
+    }
+
+    if let Some(position) = validation.highlighted {
+        let background = background_query
+            .get_single()
+            .expect("there should be only one background");
+
+        commands.entity(background).with_children(|cb| {
+            cb.spawn((
+                IssueHighlight,
+                AreaHighlightBundle::spawn(
+                    Area::between(position, position),
+                    ISSUE_RED,
+                    &dimensions,
+                ),
+            ));
+        });
+    }
+}
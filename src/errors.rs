@@ -1,5 +1,16 @@
 use std::error::Error;
 
+#[derive(Debug)]
+pub struct InvalidTransformationRule;
+
+impl std::fmt::Display for InvalidTransformationRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("invalid transformation rule")
+    }
+}
+
+impl Error for InvalidTransformationRule {}
+
 #[derive(Debug)]
 pub struct UnknownDirection;
 
@@ -21,3 +32,14 @@ impl std::fmt::Display for UnknownObjectType {
 }
 
 impl Error for UnknownObjectType {}
+
+#[derive(Debug)]
+pub struct UnknownWinCondition;
+
+impl std::fmt::Display for UnknownWinCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("unknown win condition")
+    }
+}
+
+impl Error for UnknownWinCondition {}
@@ -3,9 +3,11 @@ mod behaviors;
 mod components;
 mod object_bundles;
 mod object_type;
+mod transformations;
 
 pub use assets::*;
 pub use behaviors::*;
 pub use components::*;
 pub use object_bundles::*;
 pub use object_type::*;
+pub use transformations::*;
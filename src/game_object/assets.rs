@@ -6,22 +6,50 @@ pub const PLAYER_ASSET: &[u8] = include_bytes!("../../assets/sprites/player.png"
 
 #[derive(Clone, Default, Resource)]
 pub struct GameObjectAssets {
+    pub backslash_mirror: Handle<Image>,
     pub blue_block: Handle<Image>,
+    pub bomb: Handle<Image>,
+    pub boulder: Handle<Image>,
     pub bouncing_ball: Handle<Image>,
     pub bouncing_ball_editor: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub button: Handle<Image>,
+    pub checkpoint: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub cracked_floor: Handle<Image>,
     pub creature1: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub creature2: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub creature3: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub creature4: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub creature5: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub destructible_wall: Handle<Image>,
     pub eraser: Handle<Image>,
     pub explosion: Handle<Image>,
     pub exit: Handle<Image>,
+    pub flowing_water: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub gate: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub gem: Handle<Image>,
     pub grave: Handle<Image>,
+    pub heavy_pressure_plate: Handle<Image>,
+    pub laser_beam: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub laser_emitter: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub lava: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub lava_splash: Handle<Image>,
+    pub locked_exit: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub mine: Handle<Image>,
+    pub one_way: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub pit: Handle<Image>,
     pub player: Handle<Image>,
+    pub pressure_plate: Handle<Image>,
     pub purple_block: Handle<Image>,
     pub raft: Handle<Image>,
+    pub receiver: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub red_block: Handle<Image>,
+    pub reversing_transporter: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub rotating_transporter: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub shallow_water: (Handle<Image>, Handle<TextureAtlasLayout>),
+    pub slash_mirror: Handle<Image>,
     pub splash: Handle<Image>,
+    pub sunken_block: Handle<Image>,
+    pub swamp: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub transporter: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub water: (Handle<Image>, Handle<TextureAtlasLayout>),
     pub yellow_block: Handle<Image>,
@@ -46,9 +74,16 @@ impl GameObjectAssets {
         };
 
         Self {
+            backslash_mirror: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/backslashmirror.png"
+            ))),
             blue_block: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/blueblock.png"
             ))),
+            bomb: images.add(load_asset(include_bytes!("../../assets/sprites/bomb.png"))),
+            boulder: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/boulder.png"
+            ))),
             bouncing_ball: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/greenball.png"
             ))),
@@ -61,12 +96,48 @@ impl GameObjectAssets {
             button: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/button.png"
             ))),
+            checkpoint: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/checkpoint.png"
+                ))),
+                one_by_two_atlas.clone(),
+            ),
+            cracked_floor: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/crackedfloor.png"
+            ))),
             creature1: (
                 images.add(load_asset(include_bytes!(
                     "../../assets/sprites/creature1.png"
                 ))),
                 one_by_four_atlas.clone(),
             ),
+            creature2: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/creature2.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            creature3: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/creature3.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            creature4: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/creature4.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            creature5: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/creature5.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            destructible_wall: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/destructiblewall.png"
+            ))),
             eraser: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/eraser.png"
             ))),
@@ -74,23 +145,102 @@ impl GameObjectAssets {
             explosion: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/explosion.png"
             ))),
+            flowing_water: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/flowingwater.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
             gate: (
                 images.add(load_asset(include_bytes!("../../assets/sprites/gate.png"))),
-                one_by_two_atlas,
+                one_by_two_atlas.clone(),
             ),
+            gem: images.add(load_asset(include_bytes!("../../assets/sprites/gem.png"))),
             grave: images.add(load_asset(include_bytes!("../../assets/sprites/grave.png"))),
+            heavy_pressure_plate: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/heavypressureplate.png"
+            ))),
+            laser_beam: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/laserbeam.png"
+                ))),
+                one_by_two_atlas.clone(),
+            ),
+            laser_emitter: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/laseremitter.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            lava: (
+                images.add(load_asset(include_bytes!("../../assets/sprites/lava.png"))),
+                one_by_three_atlas.clone(),
+            ),
+            lava_splash: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/lavasplash.png"
+            ))),
+            locked_exit: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/lockedexit.png"
+                ))),
+                one_by_two_atlas.clone(),
+            ),
             mine: images.add(load_asset(include_bytes!("../../assets/sprites/mine.png"))),
+            one_way: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/oneway.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            pit: images.add(load_asset(include_bytes!("../../assets/sprites/pit.png"))),
             player: images.add(load_asset(PLAYER_ASSET)),
+            pressure_plate: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/pressureplate.png"
+            ))),
             purple_block: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/purpleblock.png"
             ))),
             raft: images.add(load_asset(include_bytes!("../../assets/sprites/raft.png"))),
+            receiver: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/receiver.png"
+                ))),
+                one_by_two_atlas,
+            ),
             red_block: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/redblock.png"
             ))),
+            reversing_transporter: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/reversingtransporter.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            rotating_transporter: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/rotatingtransporter.png"
+                ))),
+                one_by_four_atlas.clone(),
+            ),
+            shallow_water: (
+                images.add(load_asset(include_bytes!(
+                    "../../assets/sprites/shallowwater.png"
+                ))),
+                one_by_three_atlas.clone(),
+            ),
+            slash_mirror: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/slashmirror.png"
+            ))),
             splash: images.add(load_asset(include_bytes!(
                 "../../assets/sprites/splash.png"
             ))),
+            sunken_block: images.add(load_asset(include_bytes!(
+                "../../assets/sprites/sunkenblock.png"
+            ))),
+            swamp: (
+                images.add(load_asset(include_bytes!("../../assets/sprites/swamp.png"))),
+                one_by_three_atlas.clone(),
+            ),
             transporter: (
                 images.add(load_asset(include_bytes!(
                     "../../assets/sprites/transporter.png"
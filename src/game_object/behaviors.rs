@@ -1,18 +1,26 @@
-use std::{cmp::Ordering, collections::BTreeSet};
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BTreeSet, VecDeque},
+};
 
 use bevy::prelude::*;
 use rand::{thread_rng, Rng};
 
 use crate::{
-    level::Dimensions,
-    timers::{AnimationTimer, MovementTimer, TemporaryTimer, TransporterTimer},
-    Background, GameEvent, PressedTriggers, Pushable,
+    gameover::GameOverState,
+    hud::CollectedItems,
+    level::{Dimensions, WeightOverrides, WinCondition},
+    timers::{
+        AnimationTimer, CurrentTimer, ExplosionTimer, MovementTimer, TemporaryTimer,
+        TransporterTimer,
+    },
+    Background, GameEvent, PlayerEvent, PressedTriggers, Pushable, TransformEvent,
 };
 
 use super::{
     components::{Animatable, Direction, Trigger, *},
     object_bundles::*,
-    spawn_object_of_type, GameObjectAssets,
+    spawn_object_of_type, GameObjectAssets, ObjectType, TransformationRules, TransformationTrigger,
 };
 
 pub fn animate_objects(
@@ -28,6 +36,133 @@ pub fn animate_objects(
     }
 }
 
+/// Gives newly spawned objects the weights that are overridden by the level.
+pub fn apply_weight_overrides(
+    mut commands: Commands,
+    mut query: Query<(Entity, &ObjectType, Option<&mut MinWeight>), Added<ObjectType>>,
+    weight_overrides: Res<WeightOverrides>,
+) {
+    for (entity, object_type, min_weight) in &mut query {
+        let Some(weight) = weight_overrides.0.get(object_type).copied() else {
+            continue;
+        };
+
+        match min_weight {
+            Some(mut min_weight) => min_weight.0 = weight,
+            None => {
+                commands.entity(entity).insert(weight);
+            }
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn carry_passengers(
+    mut carrier_query: Query<(&Position, &mut Carrier)>,
+    mut passenger_query: Query<
+        (Entity, &mut Position, Has<Player>),
+        (OccupantFilter, Without<Carrier>),
+    >,
+    mut transform_events: EventWriter<TransformEvent>,
+) {
+    for (position, mut carrier) in &mut carrier_query {
+        if let Some(previous_position) = carrier.position.filter(|previous| previous != position) {
+            for passenger in &carrier.passengers {
+                if let Ok((_, mut passenger_position, is_player)) =
+                    passenger_query.get_mut(*passenger)
+                {
+                    // Passengers that moved by themselves stay where they are.
+                    if *passenger_position == previous_position {
+                        *passenger_position = *position;
+
+                        if is_player {
+                            transform_events.send(TransformEvent::Update);
+                        }
+                    }
+                }
+            }
+        }
+
+        carrier.passengers = passenger_query
+            .iter()
+            .filter(|(_, passenger_position, _)| *passenger_position == position)
+            .map(|(passenger, ..)| passenger)
+            .collect();
+        carrier.position = Some(*position);
+    }
+}
+
+pub fn check_for_collectibles(
+    mut commands: Commands,
+    collectible_query: Query<(Entity, &Position), With<Collectible>>,
+    player_query: Query<&Position, With<Player>>,
+    mut collected_items: ResMut<CollectedItems>,
+) {
+    for (collectible, collectible_position) in &collectible_query {
+        if player_query
+            .iter()
+            .any(|player_position| player_position == collectible_position)
+        {
+            commands.entity(collectible).despawn();
+            collected_items.num_collected += 1;
+        }
+    }
+}
+
+#[allow(clippy::type_complexity)]
+pub fn check_for_current(
+    current_query: Query<(&Direction, &Position), With<Current>>,
+    mut collision_objects_query: Query<
+        (Entity, CollisionObject, Has<Floatable>),
+        (Without<Current>, Without<Volatile>),
+    >,
+    mut timer: ResMut<CurrentTimer>,
+    dimensions: Res<Dimensions>,
+    time: Res<Time>,
+) {
+    timer.tick(time.delta());
+    if !timer.just_finished() {
+        return;
+    }
+
+    let mut already_moved = BTreeSet::new();
+    for (direction, current_position) in &current_query {
+        let (mut drifting_objects, collision_objects): (Vec<_>, Vec<_>) = collision_objects_query
+            .iter_mut()
+            .partition(|(entity, (position, ..), is_floatable)| {
+                *is_floatable
+                    && position.as_ref() == current_position
+                    && !already_moved.contains(entity)
+            });
+        if let Some((drifting, (position, _, _, _, weight, ..), _)) = drifting_objects.first_mut() {
+            // Floatable entities never drift onto dry land.
+            let (dx, dy) = direction.to_delta();
+            let (x, y) = (position.x + dx, position.y + dy);
+            let is_liquid_ahead = current_query
+                .iter()
+                .any(|(_, position)| position.x == x && position.y == y)
+                || collision_objects
+                    .iter()
+                    .any(|(_, (position, .., liquid, _), _)| {
+                        liquid.is_some() && position.x == x && position.y == y
+                    });
+            if !is_liquid_ahead {
+                continue;
+            }
+
+            move_object(
+                position,
+                direction.to_delta(),
+                &dimensions,
+                collision_objects.into_iter().map(|(_, object, _)| object),
+                weight.copied().unwrap_or_default(),
+                Weight::LIGHT,
+            );
+            already_moved.insert(*drifting);
+        }
+    }
+}
+
 pub fn check_for_deadly(
     mut commands: Commands,
     background_query: Query<Entity, With<Background>>,
@@ -57,50 +192,119 @@ pub fn check_for_exit(
     player_query: Query<&Position, With<Player>>,
     exit_query: Query<&Position, With<Exit>>,
     mut level_events: EventWriter<GameEvent>,
+    game_over_state: Res<GameOverState>,
+    win_condition: Res<WinCondition>,
 ) {
-    for player_position in &player_query {
-        for exit_position in &exit_query {
-            if player_position == exit_position {
-                level_events.send(GameEvent::LoadRelativeLevel(1));
-                return;
-            }
+    if game_over_state.is_game_over {
+        return;
+    }
+
+    let is_on_exit = |player_position: &Position| {
+        exit_query
+            .iter()
+            .any(|exit_position| exit_position == player_position)
+    };
+
+    let completed = match *win_condition {
+        WinCondition::AnyPlayerOnExit => player_query.iter().any(is_on_exit),
+        WinCondition::AllPlayersOnExits => {
+            !player_query.is_empty() && player_query.iter().all(is_on_exit)
         }
+    };
+
+    if completed {
+        level_events.send(GameEvent::LoadRelativeLevel(1));
     }
 }
 
-pub type ExplosiveSystemObject<'a> = (Entity, &'a Position, Option<&'a Explosive>);
+pub type ExplosiveSystemObject<'a> = (
+    Entity,
+    &'a Position,
+    Option<&'a Explosive>,
+    Has<Destructible>,
+    Has<Detonating>,
+);
 
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
 pub fn check_for_explosive(
     mut commands: Commands,
-    explosive_query: Query<ExplosiveSystemObject>,
+    explosive_query: Query<
+        ExplosiveSystemObject,
+        Or<(With<Explosive>, With<Destructible>, OccupantFilter)>,
+    >,
     background_query: Query<Entity, With<Background>>,
+    mut explosion_timer: ResMut<ExplosionTimer>,
     mut temporary_timer: ResMut<TemporaryTimer>,
     assets: Res<GameObjectAssets>,
+    dimensions: Res<Dimensions>,
+    time: Res<Time>,
 ) {
-    let (explosives, objects): (Vec<ExplosiveSystemObject>, Vec<ExplosiveSystemObject>) =
-        explosive_query
-            .iter()
-            .partition(|(_, _, explosive)| explosive.is_some());
+    explosion_timer.tick(time.delta());
 
-    for (explosive, explosive_position, ..) in explosives {
-        for (object, position, _) in &objects {
-            if explosive_position == *position {
-                commands.entity(explosive).despawn();
-                commands.entity(*object).despawn();
+    let (explosives, objects): (Vec<_>, Vec<_>) = explosive_query
+        .iter()
+        .partition(|(_, _, explosive, ..)| explosive.is_some());
 
-                let background = background_query
-                    .get_single()
-                    .expect("there should be only one background");
-                let mut background = commands.entity(background);
-                background.with_children(|cb| {
-                    cb.spawn(ExplosionBundle::spawn(&assets, **position));
+    let detonations: Vec<(Entity, Position, Explosive)> = explosives
+        .iter()
+        .filter_map(|(entity, position, explosive, _, is_detonating)| {
+            let explosive = explosive.copied()?;
+            let is_triggered = objects
+                .iter()
+                .any(|(_, object_position, _, is_destructible, _)| {
+                    object_position == position && !is_destructible
                 });
-                if temporary_timer.finished() {
-                    temporary_timer.reset();
-                }
+            let is_triggered = is_triggered || (*is_detonating && explosion_timer.just_finished());
+            is_triggered.then_some((*entity, **position, explosive))
+        })
+        .collect();
+    if detonations.is_empty() {
+        return;
+    }
+
+    let mut blasted_positions = BTreeSet::new();
+    for (_, origin, explosive) in &detonations {
+        let radius = explosive.blast_radius;
+        for y in (origin.y - radius).max(1)..=(origin.y + radius).min(dimensions.height) {
+            for x in (origin.x - radius).max(1)..=(origin.x + radius).min(dimensions.width) {
+                blasted_positions.insert(Position { x, y });
             }
         }
     }
+
+    for (entity, ..) in &detonations {
+        commands.entity(*entity).despawn();
+    }
+
+    for (entity, position, explosive, ..) in explosive_query.iter() {
+        if !blasted_positions.contains(position)
+            || detonations
+                .iter()
+                .any(|(detonated, ..)| *detonated == entity)
+        {
+            continue;
+        }
+
+        if explosive.is_some() {
+            // Chain reactions happen on the next explosion tick.
+            commands.entity(entity).insert(Detonating);
+        } else {
+            commands.entity(entity).despawn();
+        }
+    }
+
+    let background = background_query
+        .get_single()
+        .expect("there should be only one background");
+    let mut background = commands.entity(background);
+    background.with_children(|cb| {
+        for position in blasted_positions {
+            cb.spawn(ExplosionBundle::spawn(&assets, position));
+        }
+    });
+    if temporary_timer.finished() {
+        temporary_timer.reset();
+    }
 }
 
 pub type LiquidSystemObject<'a> = (
@@ -108,6 +312,10 @@ pub type LiquidSystemObject<'a> = (
     &'a Position,
     Option<&'a Liquid>,
     Option<&'a Floatable>,
+    Option<&'a Pushable>,
+    Option<&'a FillsLiquid>,
+    Option<&'a ObjectType>,
+    Option<&'a Weight>,
 );
 
 pub fn check_for_liquid(
@@ -116,54 +324,233 @@ pub fn check_for_liquid(
     background_query: Query<Entity, With<Background>>,
     mut temporary_timer: ResMut<TemporaryTimer>,
     assets: Res<GameObjectAssets>,
+    rules: Res<TransformationRules>,
 ) {
     let (liquids, objects): (Vec<LiquidSystemObject>, Vec<LiquidSystemObject>) = liquid_query
         .iter()
         .partition(|(_, _, liquid, ..)| liquid.is_some());
 
-    for (_liquid, liquid_position, ..) in liquids {
-        for (object, position, _, floatable) in &objects {
-            if liquid_position == *position {
-                if floatable.is_some() {
-                    if !objects.iter().any(|(other, other_position, _, floatable)| {
-                        other != object && other_position == position && floatable.is_some()
-                    }) {
-                        let mut object = commands.entity(*object);
-                        object.remove::<Pushable>();
-                    }
-                } else if !objects.iter().any(|(_, other_position, _, floatable)| {
-                    other_position == position && floatable.is_some()
-                }) {
-                    commands.entity(*object).despawn();
+    for (liquid_entity, liquid_position, liquid, _, _, liquid_fills, ..) in liquids {
+        let Some(liquid) = liquid.copied() else {
+            continue;
+        };
+
+        for (object, position, _, floatable, pushable, object_fills, object_type, weight) in
+            &objects
+        {
+            if liquid_position != *position {
+                continue;
+            }
+
+            if !liquid.sinks(floatable.is_some()) {
+                continue;
+            }
+
+            let is_carried =
+                objects
+                    .iter()
+                    .any(|(other, other_position, _, other_floatable, ..)| {
+                        other != object
+                            && other_position == position
+                            && other_floatable.is_some()
+                            && !liquid.sinks(true)
+                    });
+            if is_carried {
+                continue;
+            }
+
+            commands.entity(*object).despawn();
+
+            let background = background_query
+                .get_single()
+                .expect("there should be only one background");
+
+            let transformed_type = object_type.and_then(|object_type| {
+                rules.find(*object_type, |trigger| {
+                    trigger == TransformationTrigger::Liquid
+                })
+            });
+            if let Some(transformed_type) = transformed_type {
+                let mut background = commands.entity(background);
+                background.with_children(|cb| {
+                    spawn_object_of_type(
+                        cb,
+                        &assets,
+                        transformed_type,
+                        **position,
+                        Direction::default(),
+                    );
+                });
+                continue;
+            }
+
+            // Liquids only get filled up by heavy objects sinking into them.
+            let is_heavy = weight.copied().unwrap_or_default() >= Weight::HEAVY;
+            let fills = object_fills.or(liquid_fills.filter(|_| pushable.is_some() && is_heavy));
+            if let Some(FillsLiquid(object_type)) = fills.filter(|_| liquid.can_be_filled()) {
+                commands.entity(liquid_entity).despawn();
+
+                let mut background = commands.entity(background);
+                background.with_children(|cb| {
+                    spawn_object_of_type(
+                        cb,
+                        &assets,
+                        *object_type,
+                        **position,
+                        Direction::default(),
+                    );
+                });
+
+                // The liquid is gone, so nothing else can sink into it.
+                break;
+            }
+
+            let mut background = commands.entity(background);
+            background.with_children(|cb| {
+                cb.spawn(SplashBundle::spawn(&assets, **position, liquid));
+            });
+            if temporary_timer.finished() {
+                temporary_timer.reset();
+            }
+        }
+    }
+}
+
+/// Filter for entities that can stand on, or fall into, floor tiles.
+pub type OccupantFilter = Or<(With<Player>, With<Pushable>, With<Movable>)>;
+
+#[allow(clippy::type_complexity)]
+pub fn check_for_pit(
+    mut commands: Commands,
+    background_query: Query<Entity, With<Background>>,
+    pit_query: Query<(Entity, &Position), With<Pit>>,
+    object_query: Query<(Entity, &Position, Option<&Pushable>, Has<Player>), OccupantFilter>,
+    assets: Res<GameObjectAssets>,
+) {
+    for (pit, pit_position) in &pit_query {
+        for (object, position, pushable, is_player) in &object_query {
+            if pit_position == position {
+                commands.entity(object).despawn();
 
+                if is_player {
                     let background = background_query
                         .get_single()
                         .expect("there should be only one background");
-                    let mut background = commands.entity(background);
-                    background.with_children(|cb| {
-                        cb.spawn(SplashBundle::spawn(&assets, **position));
+                    commands.entity(background).with_children(|cb| {
+                        cb.spawn(GraveBundle::spawn(&assets, *position));
                     });
-                    if temporary_timer.finished() {
-                        temporary_timer.reset();
-                    }
+                }
+
+                if pushable.is_some() {
+                    // The pit is filled up by the pushed object.
+                    commands.entity(pit).despawn();
+                    break;
                 }
             }
         }
     }
 }
 
-#[allow(clippy::type_complexity)]
-pub fn check_for_transform_on_push(
+pub fn check_for_transform_on_leave(
     mut commands: Commands,
     background_query: Query<Entity, With<Background>>,
-    transform_query: Query<
-        (Entity, Option<&Direction>, Ref<Position>, &TransformOnPush),
-        With<Pushable>,
-    >,
+    mut transform_query: Query<(Entity, Option<&Direction>, &Position, &mut TransformOnLeave)>,
+    occupant_query: Query<&Position, OccupantFilter>,
     assets: Res<GameObjectAssets>,
 ) {
-    for (entity, direction, position, TransformOnPush(object_type)) in &transform_query {
-        if position.is_changed() && !position.is_added() {
+    for (entity, direction, position, mut transform_on_leave) in &mut transform_query {
+        let occupied = occupant_query
+            .iter()
+            .any(|occupant_position| occupant_position == position);
+        if occupied {
+            if !transform_on_leave.occupied {
+                transform_on_leave.occupied = true;
+            }
+        } else if transform_on_leave.occupied {
+            commands.entity(entity).despawn();
+
+            let background_entity = background_query
+                .get_single()
+                .expect("there should be only one background");
+
+            let mut background = commands.entity(background_entity);
+            background.with_children(|cb| {
+                spawn_object_of_type(
+                    cb,
+                    &assets,
+                    transform_on_leave.object_type,
+                    *position,
+                    direction.copied().unwrap_or_default(),
+                );
+            });
+        }
+    }
+}
+
+pub type TransformationSystemObject<'a> = (
+    Entity,
+    &'a ObjectType,
+    Option<&'a Direction>,
+    Ref<'a, Position>,
+    Option<&'a mut Age>,
+    Has<Pushable>,
+);
+
+#[allow(clippy::too_many_arguments)]
+pub fn check_for_transformations(
+    mut commands: Commands,
+    background_query: Query<Entity, With<Background>>,
+    mut transform_query: Query<TransformationSystemObject>,
+    assets: Res<GameObjectAssets>,
+    movement_timer: Res<MovementTimer>,
+    pressed_triggers: Res<PressedTriggers>,
+    rules: Res<TransformationRules>,
+) {
+    let is_triggered = pressed_triggers.is_changed() && pressed_triggers.is_triggered();
+
+    let objects: Vec<(Entity, ObjectType, Position)> = transform_query
+        .iter()
+        .map(|(entity, object_type, _, position, ..)| (entity, *object_type, *position))
+        .collect();
+
+    for (entity, object_type, direction, position, age, is_pushable) in &mut transform_query {
+        let object_rules = rules.get(*object_type);
+        if object_rules.is_empty() {
+            continue;
+        }
+
+        let age = match age {
+            Some(mut age) => {
+                if movement_timer.just_finished() {
+                    age.0 += 1;
+                }
+                age.0
+            }
+            None => {
+                if object_rules
+                    .iter()
+                    .any(|rule| matches!(rule.trigger, TransformationTrigger::Ticks(_)))
+                {
+                    commands.entity(entity).insert(Age::default());
+                }
+                0
+            }
+        };
+
+        let is_pushed = is_pushable && position.is_changed() && !position.is_added();
+        let transformed_type = rules.find(*object_type, |trigger| match trigger {
+            TransformationTrigger::Push => is_pushed,
+            TransformationTrigger::Liquid => false, // Handled by `check_for_liquid()`.
+            TransformationTrigger::Trigger => is_triggered,
+            TransformationTrigger::Ticks(ticks) => age >= ticks,
+            TransformationTrigger::Contact(contact_type) => {
+                objects.iter().any(|(other, other_type, other_position)| {
+                    *other != entity && *other_type == contact_type && other_position == &*position
+                })
+            }
+        });
+
+        if let Some(transformed_type) = transformed_type {
             commands.entity(entity).despawn();
 
             let background_entity = background_query
@@ -175,7 +562,7 @@ pub fn check_for_transform_on_push(
                 spawn_object_of_type(
                     cb,
                     &assets,
-                    *object_type,
+                    transformed_type,
                     *position,
                     direction.copied().unwrap_or_default(),
                 );
@@ -203,13 +590,16 @@ pub fn check_for_transporter(
                 .iter_mut()
                 .partition(|(_, (position, ..))| position.as_ref() == transporter_position);
         transported_objects.retain(|(entity, _)| !already_moved.contains(entity));
-        if let Some((transported, (position, ..))) = transported_objects.first_mut() {
+        if let Some((transported, (position, _, _, _, weight, ..))) =
+            transported_objects.first_mut()
+        {
             if !move_object(
                 position,
                 direction.to_delta(),
                 &dimensions,
                 collision_objects.into_iter().map(|(_, object)| object),
-                Weight::Light,
+                weight.copied().unwrap_or_default(),
+                Weight::LIGHT,
             ) {
                 // If an object on a transporter cannot be moved, the
                 // transporter's [BlocksMovement] component is disabled until
@@ -227,55 +617,134 @@ pub type TriggerSystemObject<'a> = (
     Option<&'a Openable>,
     Option<&'a Massive>,
     Option<&'a Trigger>,
+    Option<&'a MinWeight>,
+    Option<&'a Receiver>,
+    Option<&'a Channel>,
     Option<&'a mut TextureAtlas>,
 );
 
 pub fn check_for_triggers(
     mut commands: Commands,
     mut query: Query<TriggerSystemObject>,
+    mut switchable_query: Query<(&Switchable, &mut Direction, Option<&Channel>)>,
+    weight_query: Query<(&Position, Option<&Weight>), OccupantFilter>,
     mut pressed_triggers: ResMut<PressedTriggers>,
 ) {
     let mut triggers = Vec::new();
     let mut openables = Vec::new();
     let mut objects = Vec::new();
-    for (entity, position, openable, massive, trigger, atlas) in &mut query {
+    for (entity, position, openable, massive, trigger, min_weight, receiver, channel, atlas) in
+        &mut query
+    {
+        let channel = channel.copied().unwrap_or_default();
         if trigger.is_some() {
-            triggers.push(position);
+            triggers.push((channel, position, min_weight, receiver));
         } else if openable.is_some() {
-            openables.push((entity, massive, atlas));
+            openables.push((channel, entity, massive, atlas));
         } else {
             objects.push(position);
         }
     }
 
-    let num_pressed_triggers = triggers
-        .iter()
-        .filter(|trigger_position| objects.iter().any(|position| position == *trigger_position))
-        .count();
+    let mut num_pressed_triggers: BTreeMap<Channel, usize> = BTreeMap::new();
+    for (channel, trigger_position, min_weight, receiver) in triggers {
+        let is_pressed = match (min_weight, receiver) {
+            (_, Some(receiver)) => receiver.activated,
+            (Some(MinWeight(min_weight)), None) => {
+                let weight: Weight = weight_query
+                    .iter()
+                    .filter(|(position, _)| *position == trigger_position)
+                    .map(|(_, weight)| weight.copied().unwrap_or_default())
+                    .sum();
+                weight >= *min_weight
+            }
+            (None, None) => objects.contains(&trigger_position),
+        };
+        if is_pressed {
+            *num_pressed_triggers.entry(channel).or_default() += 1;
+        }
+    }
 
-    let opened = match num_pressed_triggers.cmp(&pressed_triggers.num_pressed_triggers) {
-        Ordering::Greater => true,
-        Ordering::Less => false,
-        Ordering::Equal => return, // No change.
-    };
+    if num_pressed_triggers == pressed_triggers.num_pressed_triggers {
+        return; // No change.
+    }
 
-    for (openable, massive, atlas) in openables {
-        if opened && massive.is_some() {
-            commands.entity(openable).remove::<Massive>();
+    let channels: BTreeSet<Channel> = num_pressed_triggers
+        .keys()
+        .chain(pressed_triggers.num_pressed_triggers.keys())
+        .copied()
+        .collect();
+    for channel in channels {
+        let num_pressed = num_pressed_triggers
+            .get(&channel)
+            .copied()
+            .unwrap_or_default();
+        let num_previously_pressed = pressed_triggers
+            .num_pressed_triggers
+            .get(&channel)
+            .copied()
+            .unwrap_or_default();
+        let opened = match num_pressed.cmp(&num_previously_pressed) {
+            Ordering::Greater => true,
+            Ordering::Less => false,
+            Ordering::Equal => continue, // No change.
+        };
 
-            if let Some(mut atlas) = atlas {
-                atlas.index = 1;
+        for (_, openable, massive, atlas) in openables
+            .iter_mut()
+            .filter(|(openable_channel, ..)| *openable_channel == channel)
+        {
+            if opened && massive.is_some() {
+                commands.entity(*openable).remove::<Massive>();
+
+                if let Some(atlas) = atlas {
+                    atlas.index = 1;
+                }
+            } else if !opened && massive.is_none() {
+                commands.entity(*openable).insert(Massive);
+
+                if let Some(atlas) = atlas {
+                    atlas.index = 0;
+                }
             }
-        } else if !opened && massive.is_none() {
-            commands.entity(openable).insert(Massive);
+        }
 
-            if let Some(mut atlas) = atlas {
-                atlas.index = 0;
+        if opened {
+            for (switchable, mut direction, switchable_channel) in &mut switchable_query {
+                if switchable_channel.copied().unwrap_or_default() == channel {
+                    *direction = switchable.switch(*direction);
+                }
             }
         }
     }
 
-    pressed_triggers.num_pressed_triggers = num_pressed_triggers;
+    pressed_triggers.num_previously_pressed_triggers = std::mem::replace(
+        &mut pressed_triggers.num_pressed_triggers,
+        num_pressed_triggers,
+    );
+}
+
+pub fn check_for_locked_exits(
+    mut commands: Commands,
+    mut locked_exit_query: Query<(Entity, Has<Massive>, &mut TextureAtlas), With<LockedExit>>,
+    collectible_query: Query<(), With<Collectible>>,
+) {
+    let unlocked = collectible_query.is_empty();
+    for (locked_exit, massive, mut atlas) in &mut locked_exit_query {
+        if unlocked && massive {
+            commands
+                .entity(locked_exit)
+                .remove::<Massive>()
+                .insert(Exit);
+            atlas.index = 1;
+        } else if !unlocked && !massive {
+            commands
+                .entity(locked_exit)
+                .remove::<Exit>()
+                .insert(Massive);
+            atlas.index = 0;
+        }
+    }
 }
 
 pub fn despawn_volatile_objects(
@@ -299,11 +768,25 @@ pub type CollisionObject<'a> = (
     Option<&'a BlocksPushes>,
     Option<&'a Weight>,
     Option<Mut<'a, BlocksMovement>>,
+    Option<(&'a OneWay, &'a Direction)>,
+    Option<&'a Floatable>,
+    Option<&'a Liquid>,
+    Option<Mut<'a, Rolling>>,
 );
 
+#[allow(clippy::type_complexity)]
 pub fn move_objects(
-    mut movable_query: Query<(&mut Direction, &Movable, &mut Position, Option<&Weight>)>,
-    mut collision_objects_query: Query<CollisionObject, Without<Movable>>,
+    mut movable_query: Query<(
+        &mut Direction,
+        &Movable,
+        &mut Position,
+        Option<&Weight>,
+        Option<&mut Waypoints>,
+    )>,
+    mut collision_objects_query: Query<
+        (CollisionObject, Has<Liquid>, Has<Player>),
+        Without<Movable>,
+    >,
     mut timer: ResMut<MovementTimer>,
     dimensions: Res<Dimensions>,
     time: Res<Time>,
@@ -313,47 +796,372 @@ pub fn move_objects(
         return;
     }
 
-    for (mut direction, movable, mut position, weight) in &mut movable_query {
+    let mut player_positions = Vec::new();
+    let mut obstacles = BTreeSet::new();
+    for ((position, _, massive, ..), is_liquid, is_player) in collision_objects_query.iter() {
+        if is_player {
+            player_positions.push(*position);
+        } else if massive.is_some() || is_liquid {
+            obstacles.insert(*position);
+        }
+    }
+
+    for (mut direction, movable, mut position, weight, waypoints) in &mut movable_query {
+        let weight = weight.copied().unwrap_or_default();
+        let mut move_towards = |position: &mut Mut<Position>, direction: Direction| {
+            move_object(
+                position,
+                direction.to_delta(),
+                &dimensions,
+                collision_objects_query
+                    .iter_mut()
+                    .map(|(collision_object, ..)| collision_object),
+                weight,
+                weight,
+            )
+        };
+
         match movable {
             Movable::Bounce => {
-                if !move_object(
-                    &mut position,
-                    direction.to_delta(),
-                    &dimensions,
-                    collision_objects_query.iter_mut(),
-                    weight.copied().unwrap_or_default(),
-                ) {
+                if !move_towards(&mut position, *direction) {
                     *direction = direction.inverse();
                 }
             }
             Movable::FollowRightHand => {
-                if move_object(
-                    &mut position,
-                    direction.right_hand().to_delta(),
-                    &dimensions,
-                    collision_objects_query.iter_mut(),
-                    weight.copied().unwrap_or_default(),
-                ) {
+                if move_towards(&mut position, direction.right_hand()) {
                     *direction = direction.right_hand();
-                } else if !move_object(
-                    &mut position,
-                    direction.to_delta(),
-                    &dimensions,
-                    collision_objects_query.iter_mut(),
-                    weight.copied().unwrap_or_default(),
-                ) {
+                } else if !move_towards(&mut position, *direction) {
+                    *direction = direction.left_hand();
+                }
+            }
+            Movable::FollowLeftHand => {
+                if move_towards(&mut position, direction.left_hand()) {
                     *direction = direction.left_hand();
+                } else if !move_towards(&mut position, *direction) {
+                    *direction = direction.right_hand();
+                }
+            }
+            Movable::ChasePlayer => {
+                if let Some(next_direction) =
+                    find_path(*position, &player_positions, &obstacles, &dimensions)
+                {
+                    if *direction != next_direction {
+                        *direction = next_direction;
+                    }
+                    move_towards(&mut position, next_direction);
                 }
             }
+            Movable::Patrol => {
+                let Some(mut waypoints) = waypoints else {
+                    continue;
+                };
+                if waypoints.positions.is_empty() {
+                    continue;
+                }
+
+                if waypoints.positions[waypoints.next] == *position {
+                    waypoints.next = (waypoints.next + 1) % waypoints.positions.len();
+                }
+
+                let target = waypoints.positions[waypoints.next];
+                let (dx, dy) = (target.x - position.x, target.y - position.y);
+                let horizontal = Direction::from_delta((dx.signum(), 0));
+                let vertical = Direction::from_delta((0, dy.signum()));
+                let candidates = if dx.abs() >= dy.abs() {
+                    [horizontal, vertical]
+                } else {
+                    [vertical, horizontal]
+                };
+                for next_direction in candidates.into_iter().flatten() {
+                    if move_towards(&mut position, next_direction) {
+                        if *direction != next_direction {
+                            *direction = next_direction;
+                        }
+                        break;
+                    }
+                }
+            }
+            Movable::Mirror => {} // Moved by `move_mirroring_objects()` instead.
+        }
+    }
+}
+
+pub fn move_mirroring_objects(
+    mut events: EventReader<PlayerEvent>,
+    mut movable_query: Query<(&mut Direction, &Movable, &mut Position, Option<&Weight>)>,
+    mut collision_objects_query: Query<CollisionObject, Without<Movable>>,
+    dimensions: Res<Dimensions>,
+) {
+    for event in events.read() {
+        let PlayerEvent::Moved(dx, dy) = event else {
+            continue;
+        };
+
+        let Some(mirrored_direction) = Direction::from_delta((-dx, -dy)) else {
+            continue;
+        };
+
+        for (mut direction, movable, mut position, weight) in &mut movable_query {
+            if !matches!(movable, Movable::Mirror) {
+                continue;
+            }
+
+            if *direction != mirrored_direction {
+                *direction = mirrored_direction;
+            }
+
+            move_object(
+                &mut position,
+                mirrored_direction.to_delta(),
+                &dimensions,
+                collision_objects_query.iter_mut(),
+                weight.copied().unwrap_or_default(),
+                weight.copied().unwrap_or_default(),
+            );
+        }
+    }
+}
+
+/// Returns the direction of the first step on the shortest path from `from` to
+/// the nearest of the given `targets`, without passing any of the `obstacles`.
+fn find_path(
+    from: Position,
+    targets: &[Position],
+    obstacles: &BTreeSet<Position>,
+    dimensions: &Dimensions,
+) -> Option<Direction> {
+    let mut visited = BTreeSet::from([from]);
+    let mut queue = VecDeque::new();
+    queue.push_back((from, None));
+
+    while let Some((position, first_direction)) = queue.pop_front() {
+        if targets.contains(&position) {
+            return first_direction;
+        }
+
+        for direction in [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ] {
+            let (dx, dy) = direction.to_delta();
+            let next = Position {
+                x: position.x + dx,
+                y: position.y + dy,
+            };
+            if next.x < 1
+                || next.x > dimensions.width
+                || next.y < 1
+                || next.y > dimensions.height
+                || obstacles.contains(&next)
+                || !visited.insert(next)
+            {
+                continue;
+            }
+
+            queue.push_back((next, first_direction.or(Some(direction))));
+        }
+    }
+
+    None
+}
+
+#[allow(clippy::type_complexity)]
+pub fn roll_objects(
+    mut commands: Commands,
+    mut collision_objects_query: Query<
+        (Entity, CollisionObject, Has<Player>),
+        (Without<Movable>, Without<Volatile>),
+    >,
+    creature_query: Query<(Entity, &Position), (With<Deadly>, With<Movable>)>,
+    timer: Res<MovementTimer>,
+    dimensions: Res<Dimensions>,
+) {
+    // The movement timer is ticked by `move_objects()`.
+    if !timer.just_finished() {
+        return;
+    }
+
+    let rolling_entities: Vec<_> = collision_objects_query
+        .iter()
+        .filter(|(_, (.., rolling), _)| {
+            rolling
+                .as_ref()
+                .is_some_and(|rolling| rolling.direction.is_some())
+        })
+        .map(|(entity, ..)| entity)
+        .collect();
+    for rolling_entity in rolling_entities {
+        let (mut rolling_objects, collision_objects): (Vec<_>, Vec<_>) = collision_objects_query
+            .iter_mut()
+            .partition(|(entity, ..)| *entity == rolling_entity);
+        let Some((_, (position, _, _, _, weight, .., Some(rolling)), _)) =
+            rolling_objects.first_mut()
+        else {
+            continue;
+        };
+        let Some(direction) = rolling.direction else {
+            continue;
+        };
+
+        let (dx, dy) = direction.to_delta();
+        let is_blocked_by_player =
+            collision_objects
+                .iter()
+                .any(|(_, (player_position, ..), is_player)| {
+                    *is_player
+                        && player_position.x == position.x + dx
+                        && player_position.y == position.y + dy
+                });
+        if is_blocked_by_player
+            || !move_object(
+                position,
+                (dx, dy),
+                &dimensions,
+                collision_objects.into_iter().map(|(_, object, ..)| object),
+                weight.copied().unwrap_or_default(),
+                Weight::LIGHT,
+            )
+        {
+            rolling.direction = None;
+            continue;
+        }
+
+        for (creature, creature_position) in &creature_query {
+            if creature_position == position.as_ref() {
+                commands.entity(creature).despawn();
+            }
+        }
+    }
+}
+
+pub type LaserChangeFilter = Or<(Changed<Position>, Changed<Direction>, Added<Massive>)>;
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn update_lasers(
+    mut commands: Commands,
+    background_query: Query<Entity, With<Background>>,
+    beam_query: Query<Entity, With<LaserBeam>>,
+    emitter_query: Query<(Entity, &Direction, &Position), With<LaserEmitter>>,
+    object_query: Query<(&Position, Has<Massive>, Option<&Mirror>), Without<Receiver>>,
+    mut receiver_query: Query<(Entity, &Position, &mut Receiver, &mut TextureAtlas)>,
+    player_query: Query<(Entity, &Position), With<Player>>,
+    changed_query: Query<(), LaserChangeFilter>,
+    (mut removed_positions, mut removed_massives): (
+        RemovedComponents<Position>,
+        RemovedComponents<Massive>,
+    ),
+    assets: Res<GameObjectAssets>,
+    dimensions: Res<Dimensions>,
+) {
+    let removed = removed_positions.read().count() + removed_massives.read().count() > 0;
+    if changed_query.is_empty() && !removed && !dimensions.is_changed() {
+        return;
+    }
+
+    for beam in &beam_query {
+        commands.entity(beam).despawn();
+    }
+
+    let mut activated_receivers = BTreeSet::new();
+    let mut killed_players = BTreeSet::new();
+    for (emitter, direction, emitter_position) in &emitter_query {
+        let mut direction = *direction;
+        let mut position = *emitter_position;
+        let mut visited = BTreeSet::new();
+        let mut segments = Vec::new();
+        loop {
+            let (dx, dy) = direction.to_delta();
+            position = Position {
+                x: position.x + dx,
+                y: position.y + dy,
+            };
+            if position.x < 1
+                || position.x > dimensions.width
+                || position.y < 1
+                || position.y > dimensions.height
+                || !visited.insert((position, direction))
+            {
+                break;
+            }
+
+            if let Some((receiver, ..)) = receiver_query
+                .iter()
+                .find(|(_, receiver_position, ..)| **receiver_position == position)
+            {
+                activated_receivers.insert(receiver);
+                break;
+            }
+
+            let objects: Vec<_> = object_query
+                .iter()
+                .filter(|(object_position, ..)| **object_position == position)
+                .collect();
+            if let Some(mirror) = objects.iter().find_map(|(_, _, mirror)| *mirror) {
+                segments.push((position, direction));
+                direction = mirror.reflect(direction);
+                segments.push((position, direction));
+                continue;
+            }
+
+            if objects.iter().any(|(_, massive, _)| *massive) {
+                break;
+            }
+
+            segments.push((position, direction));
+            killed_players.extend(
+                player_query
+                    .iter()
+                    .filter(|(_, player_position)| **player_position == position)
+                    .map(|(player, player_position)| (player, *player_position)),
+            );
+        }
+
+        commands.entity(emitter).with_children(|cb| {
+            for (position, direction) in segments {
+                let offset = (
+                    position.x - emitter_position.x,
+                    position.y - emitter_position.y,
+                );
+                cb.spawn(LaserBeamBundle::spawn(&assets, offset, direction));
+            }
+        });
+    }
+
+    for (receiver, _, mut receiver_state, mut atlas) in &mut receiver_query {
+        let activated = activated_receivers.contains(&receiver);
+        if receiver_state.activated != activated {
+            receiver_state.activated = activated;
+            atlas.index = if activated { 1 } else { 0 };
         }
     }
+
+    if killed_players.is_empty() {
+        return;
+    }
+
+    let background = background_query
+        .get_single()
+        .expect("there should be only one background");
+    for (player, player_position) in killed_players {
+        commands.entity(player).despawn();
+        commands.entity(background).with_children(|cb| {
+            cb.spawn(GraveBundle::spawn(&assets, player_position));
+        });
+    }
 }
 
+/// Moves an object with the given weight by the given delta, pushing any
+/// pushable entities in its way that are no heavier than `max_weight`.
+///
+/// Returns whether the object was moved.
 pub fn move_object<'a>(
     object_position: &mut Mut<Position>,
     (dx, dy): (i16, i16),
     dimensions: &Dimensions,
     collision_objects: impl Iterator<Item = CollisionObject<'a>>,
+    weight: Weight,
     max_weight: Weight,
 ) -> bool {
     let new_x = object_position.x + dx;
@@ -383,13 +1191,34 @@ pub fn move_object<'a>(
         (position.x - new_x).abs() + (position.y - new_y).abs()
     });
 
-    let can_push_to = |x: i16, y: i16| -> bool {
+    // One-way tiles may only be entered and exited in their own direction.
+    let can_pass = |x: i16, y: i16| -> bool {
+        !collision_objects
+            .iter()
+            .any(|(position, .., one_way, _, _, _)| {
+                position.x == x
+                    && position.y == y
+                    && one_way.is_some_and(|(_, direction)| direction.to_delta() != (dx, dy))
+            })
+    };
+
+    if !can_pass(object_position.x, object_position.y) || !can_pass(new_x, new_y) {
+        return false;
+    }
+
+    let can_push_to = |x: i16, y: i16, weight: Weight| -> bool {
         if x < 1 || x > dimensions.width || y < 1 || y > dimensions.height {
             return false;
         }
-        for (position, pushable, massive, blocks_pushes, ..) in &collision_objects {
+        if !can_pass(x, y) {
+            return false;
+        }
+        for (position, pushable, massive, blocks_pushes, .., liquid, _) in &collision_objects {
             let has_target_position = position.x == x && position.y == y;
-            let can_push_to = !pushable.is_some() && !massive.is_some() && !blocks_pushes.is_some();
+            let can_push_to = !pushable.is_some()
+                && !massive.is_some()
+                && !blocks_pushes.is_some()
+                && !liquid.is_some_and(|liquid| liquid.blocks(weight));
             if has_target_position && !can_push_to {
                 return false;
             }
@@ -398,8 +1227,10 @@ pub fn move_object<'a>(
     };
 
     let mut pushed_object_indices = Vec::new();
-    for (index, (position, pushable, massive, _, weight, blocks_movement)) in
-        collision_objects.iter().enumerate()
+    for (
+        index,
+        (position, pushable, massive, _, pushed_weight, blocks_movement, _, floatable, liquid, _),
+    ) in collision_objects.iter().enumerate()
     {
         if position.as_ref() == object_position.as_ref()
             && blocks_movement
@@ -410,8 +1241,19 @@ pub fn move_object<'a>(
         }
 
         if position.x == new_x && position.y == new_y {
-            let weight = weight.copied().unwrap_or_default();
-            if weight <= max_weight && pushable.is_some() && can_push_to(new_x + dx, new_y + dy) {
+            if liquid.is_some_and(|liquid| liquid.blocks(weight)) {
+                return false;
+            }
+
+            // Floating entities can be boarded instead of being pushed away.
+            let is_floating = floatable.is_some() && has_liquid(&collision_objects, new_x, new_y);
+
+            let pushed_weight = pushed_weight.copied().unwrap_or_default();
+            if !is_floating
+                && pushed_weight <= max_weight
+                && pushable.is_some()
+                && can_push_to(new_x + dx, new_y + dy, pushed_weight)
+            {
                 pushed_object_indices.push(index);
                 continue;
             }
@@ -423,12 +1265,17 @@ pub fn move_object<'a>(
     }
 
     for index in pushed_object_indices {
-        let position = &mut collision_objects[index].0;
+        let (position, .., rolling) = &mut collision_objects[index];
         position.x += dx;
         position.y += dy;
+
+        // Rolling entities start rolling in the direction they are pushed.
+        if let Some(rolling) = rolling {
+            rolling.direction = Direction::from_delta((dx, dy));
+        }
     }
 
-    for (position, .., blocks_movement) in &mut collision_objects {
+    for (position, .., blocks_movement, _, _, _, _) in &mut collision_objects {
         if let Some(blocks_movement) = blocks_movement {
             if position.as_ref() == object_position.as_ref() {
                 **blocks_movement = BlocksMovement::Enabled;
@@ -440,3 +1287,63 @@ pub fn move_object<'a>(
     object_position.y = new_y;
     true
 }
+
+fn has_liquid(collision_objects: &[CollisionObject], x: i16, y: i16) -> bool {
+    collision_objects
+        .iter()
+        .any(|(position, .., liquid, _)| liquid.is_some() && position.x == x && position.y == y)
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy::ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    fn setup_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<GameObjectAssets>();
+        world.init_resource::<TemporaryTimer>();
+        world.init_resource::<TransformationRules>();
+        world.spawn(Background);
+        world
+    }
+
+    fn count_objects(world: &mut World, object_type: ObjectType) -> usize {
+        world
+            .query::<&ObjectType>()
+            .iter(world)
+            .filter(|other| **other == object_type)
+            .count()
+    }
+
+    #[test]
+    fn heavy_block_fills_swamp() {
+        let mut world = setup_world();
+        let assets = world.resource::<GameObjectAssets>().clone();
+        let position = Position { x: 1, y: 1 };
+        world.spawn(SwampBundle::spawn(&assets, position));
+        world.spawn(BlueBlockBundle::spawn(&assets, position));
+
+        world.run_system_once(check_for_liquid);
+
+        assert_eq!(count_objects(&mut world, ObjectType::BlueBlock), 0);
+        assert_eq!(count_objects(&mut world, ObjectType::Swamp), 0);
+        assert_eq!(count_objects(&mut world, ObjectType::SunkenBlock), 1);
+    }
+
+    #[test]
+    fn light_block_sinks_without_filling_swamp() {
+        let mut world = setup_world();
+        let assets = world.resource::<GameObjectAssets>().clone();
+        let position = Position { x: 1, y: 1 };
+        world.spawn(SwampBundle::spawn(&assets, position));
+        world.spawn(PurpleBlockBundle::spawn(&assets, position));
+
+        world.run_system_once(check_for_liquid);
+
+        assert_eq!(count_objects(&mut world, ObjectType::PurpleBlock), 0);
+        assert_eq!(count_objects(&mut world, ObjectType::Swamp), 1);
+        assert_eq!(count_objects(&mut world, ObjectType::SunkenBlock), 0);
+    }
+}
@@ -1,4 +1,4 @@
-use std::{fmt::Display, str::FromStr};
+use std::{fmt::Display, iter::Sum, str::FromStr};
 
 use bevy::prelude::*;
 
@@ -45,6 +45,17 @@ impl Display for Direction {
 }
 
 impl Direction {
+    /// Returns the direction matching a `(dx, dy)` tuple, if any.
+    pub fn from_delta(delta: (i16, i16)) -> Option<Self> {
+        match delta {
+            (0, -1) => Some(Self::Up),
+            (1, 0) => Some(Self::Right),
+            (0, 1) => Some(Self::Down),
+            (-1, 0) => Some(Self::Left),
+            _ => None,
+        }
+    }
+
     pub fn inverse(self) -> Self {
         match self {
             Self::Up => Self::Down,
@@ -97,6 +108,17 @@ impl FromStr for Direction {
     }
 }
 
+/// Marks the [Player] entity that is currently being controlled.
+///
+/// There is at most one active player at a time.
+#[derive(Component)]
+pub struct ActivePlayer;
+
+/// Number of movement ticks since the entity spawned. Only tracked for
+/// entities that transform after a number of ticks.
+#[derive(Component, Default)]
+pub struct Age(pub u16);
+
 #[derive(Component)]
 pub struct Animatable {
     pub num_frames: usize,
@@ -118,32 +140,148 @@ pub enum BlocksMovement {
 #[derive(Component)]
 pub struct BlocksPushes;
 
+/// Entity carries along the entities standing on it whenever it moves.
+#[derive(Component, Default)]
+pub struct Carrier {
+    /// Entities that were standing on it as of the last check.
+    pub passengers: Vec<Entity>,
+
+    /// Position of the carrier as of the last check.
+    pub position: Option<Position>,
+}
+
+/// Wires [Trigger] entities to the [Openable] and [Switchable] entities they
+/// control. Pressing a trigger only affects the entities on its own channel.
+///
+/// Entities without an explicit channel are on channel 0.
+#[derive(Clone, Component, Copy, Debug, Default, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Channel(pub u8);
+
+/// A floor tile that saves the state of the board when a player steps onto
+/// it, so that players can respawn there after dying.
+#[derive(Component)]
+pub struct Checkpoint;
+
+/// Collectible entities are picked up by players stepping onto them.
+///
+/// A [LockedExit] only opens once all collectibles in the level have been
+/// collected.
+#[derive(Component)]
+pub struct Collectible;
+
+/// A [Liquid] entity that makes [Floatable] entities on it drift towards its
+/// [Direction].
+#[derive(Component)]
+pub struct Current;
+
 /// A deadly entity will kill the player if it comes into contact with it.
 #[derive(Component)]
 pub struct Deadly;
 
+/// Destructible entities are destroyed when they are caught in the blast of an
+/// [Explosive].
+#[derive(Component)]
+pub struct Destructible;
+
+/// Marks an [Explosive] that was caught in the blast of another explosive. It
+/// will detonate on the next explosion tick.
+#[derive(Component)]
+pub struct Detonating;
+
 /// An exit completes the level when stepped on.
 #[derive(Component)]
 pub struct Exit;
 
 /// Explodes on contact.
 ///
+/// The blast destroys the players, pushable and movable entities, as well as
+/// [Destructible] entities, within the blast radius. Other explosives within
+/// the blast radius detonate as well, causing a chain reaction.
+///
 /// Should not be combined with [Deadly]. Dying is implied if the player
 /// explodes.
+#[derive(Clone, Component, Copy)]
+pub struct Explosive {
+    /// Number of squares the blast reaches in every direction, including
+    /// diagonally. A radius of 0 only affects the explosive's own square.
+    pub blast_radius: i16,
+}
+
+/// Decides what happens when an entity sinks into a [Liquid]: Both the sinking
+/// entity and the liquid are replaced with an entity of the given type.
+///
+/// When placed on a sinking entity, it fills up any liquid it sinks into. When
+/// placed on a liquid, it gets filled up by any [Pushable] entity of at least
+/// [Weight::HEAVY] that sinks into it. Without this, sinking entities simply
+/// disappear.
 #[derive(Component)]
-pub struct Explosive;
+pub struct FillsLiquid(pub ObjectType);
 
 /// A floatable entity will not sink when it comes into contact with a liquid.
+///
+/// Floatable entities only drift onto liquids, and are boarded rather than
+/// pushed while they float.
 #[derive(Component)]
 pub struct Floatable;
 
+/// Part of the beam emitted by a [LaserEmitter]. Beam entities are spawned as
+/// children of the emitter and are replaced whenever the beam changes.
+#[derive(Component)]
+pub struct LaserBeam;
+
+/// Emits a laser beam towards its [Direction].
+///
+/// The beam is reflected by [Mirror] entities, stopped by [Massive] entities
+/// and activates any [Receiver] it hits. Players caught in the beam die.
+#[derive(Component)]
+pub struct LaserEmitter;
+
+/// An exit that remains [Massive] until all [Collectible] entities in the
+/// level have been collected. Only once it opens, does it become an [Exit].
+#[derive(Component)]
+pub struct LockedExit;
+
 /// Liquid entities will cause other entities to sink when it comes into
-/// contact with them. An exception are [Floatable] entities.
+/// contact with them. Which entities sink depends on the kind of liquid.
 ///
 /// Should not be combined with [Deadly]. Dying is implied if the player
 /// sinks.
-#[derive(Component)]
-pub struct Liquid;
+#[derive(Clone, Component, Copy, Debug, Eq, PartialEq)]
+pub enum Liquid {
+    /// Everything sinks, except [Floatable] entities and the entities they
+    /// carry.
+    Water,
+
+    /// Everything sinks, including [Floatable] entities. Cannot be filled up
+    /// using [FillsLiquid].
+    Lava,
+
+    /// Nothing sinks, but [Weight::LIGHT] entities cannot enter it, while
+    /// heavier entities wade through.
+    ShallowWater,
+}
+
+impl Liquid {
+    /// Returns whether the liquid can be filled up by entities sinking into it.
+    pub fn can_be_filled(self) -> bool {
+        self != Self::Lava
+    }
+
+    /// Returns whether the liquid keeps an entity with the given weight from
+    /// moving onto it.
+    pub fn blocks(self, weight: Weight) -> bool {
+        self == Self::ShallowWater && weight <= Weight::LIGHT
+    }
+
+    /// Returns whether an entity sinks into the liquid.
+    pub fn sinks(self, floatable: bool) -> bool {
+        match self {
+            Self::Water => !floatable,
+            Self::Lava => true,
+            Self::ShallowWater => false,
+        }
+    }
+}
 
 /// A massive entity will prevent other entities from moving onto it.
 ///
@@ -152,6 +290,42 @@ pub struct Liquid;
 #[derive(Component)]
 pub struct Massive;
 
+/// Reflects laser beams by 90 degrees. The variant describes the orientation
+/// of the mirror's surface.
+#[derive(Clone, Component, Copy)]
+pub enum Mirror {
+    /// Mirror running from bottom-left to top-right.
+    Slash,
+
+    /// Mirror running from top-left to bottom-right.
+    Backslash,
+}
+
+impl Mirror {
+    /// Returns the direction of a beam travelling in the given direction after
+    /// it is reflected.
+    pub fn reflect(self, direction: Direction) -> Direction {
+        match (self, direction) {
+            (Self::Slash, Direction::Up) => Direction::Right,
+            (Self::Slash, Direction::Right) => Direction::Up,
+            (Self::Slash, Direction::Down) => Direction::Left,
+            (Self::Slash, Direction::Left) => Direction::Down,
+            (Self::Backslash, Direction::Up) => Direction::Left,
+            (Self::Backslash, Direction::Right) => Direction::Down,
+            (Self::Backslash, Direction::Down) => Direction::Right,
+            (Self::Backslash, Direction::Left) => Direction::Up,
+        }
+    }
+}
+
+/// A [Trigger] that is only pressed if the combined [Weight] of the entities
+/// on it is at least the given weight.
+///
+/// Levels can override the minimum weight per object type in their `[Weights]`
+/// section.
+#[derive(Component)]
+pub struct MinWeight(pub Weight);
+
 /// Movable entities move by themselves.
 ///
 /// They face a given [Direction], while the [Movable] variant decides what will
@@ -165,12 +339,43 @@ pub enum Movable {
     /// Turns right whenever they can, while following whatever obstacles they
     /// have on their right.
     FollowRightHand,
+
+    /// Turns left whenever they can, while following whatever obstacles they
+    /// have on their left.
+    FollowLeftHand,
+
+    /// Takes the shortest path towards the nearest player, avoiding [Massive]
+    /// and [Liquid] entities.
+    ChasePlayer,
+
+    /// Moves towards each of its [Waypoints] in turn. Stays in place if it has
+    /// none.
+    Patrol,
+
+    /// Moves in the opposite direction whenever the player moves.
+    Mirror,
 }
 
-/// A [Massive] entity that can be opened by pressing a [Trigger].
+/// A floor tile that can only be entered and exited by moving in the tile's
+/// [Direction].
+///
+/// This applies to all entities, including those that are being pushed.
+#[derive(Component)]
+pub struct OneWay;
+
+/// A [Massive] entity that can be opened by pressing a [Trigger] on the same
+/// [Channel].
 #[derive(Component)]
 pub struct Openable;
 
+/// Entities that move onto a pit fall in and are destroyed. A [Pushable]
+/// entity fills up the pit, after which it becomes regular floor again.
+///
+/// Should not be combined with [Deadly]. Dying is implied if the player falls
+/// in.
+#[derive(Component)]
+pub struct Pit;
+
 /// Entity is controlled by the player.
 #[derive(Component)]
 pub struct Player;
@@ -183,9 +388,48 @@ pub struct Player;
 #[derive(Component)]
 pub struct Pushable;
 
-/// After pushing, entity transforms into another of the given type.
+/// A [Trigger] that is pressed while it is hit by the beam of a
+/// [LaserEmitter], rather than by entities standing on it.
+///
+/// Like any trigger, it only opens and switches entities on its own
+/// [Channel].
+#[derive(Component, Default)]
+pub struct Receiver {
+    pub activated: bool,
+}
+
+/// Once pushed, entity keeps rolling in the direction it was pushed until it
+/// gets blocked.
+///
+/// Rolling entities crush the [Deadly] [Movable] entities in their path.
+#[derive(Component, Default)]
+pub struct Rolling {
+    /// Direction the entity is rolling in, if it is rolling at all. Set by
+    /// `move_object()` when the entity gets pushed.
+    pub direction: Option<Direction>,
+}
+
+/// After an entity that was standing on it leaves, entity transforms into
+/// another of the given type.
+///
+/// Only the [Player], [Pushable] and [Movable] entities count as standing on
+/// it.
 #[derive(Component)]
-pub struct TransformOnPush(pub ObjectType);
+pub struct TransformOnLeave {
+    pub object_type: ObjectType,
+
+    /// Whether an entity is currently standing on it.
+    pub occupied: bool,
+}
+
+impl TransformOnLeave {
+    pub fn new(object_type: ObjectType) -> Self {
+        Self {
+            object_type,
+            occupied: false,
+        }
+    }
+}
 
 /// Entity pushes all other entities that are placed on it towards a given
 /// [Direction].
@@ -195,7 +439,29 @@ pub struct TransformOnPush(pub ObjectType);
 #[derive(Component)]
 pub struct Transporter;
 
-/// Entity acts as trigger for opening gates.
+/// Entity changes its [Direction] whenever a [Trigger] on the same [Channel]
+/// gets pressed.
+#[derive(Clone, Component, Copy)]
+pub enum Switchable {
+    /// Rotates the direction 90 degrees clockwise.
+    Rotate,
+
+    /// Reverses the direction.
+    Reverse,
+}
+
+impl Switchable {
+    /// Returns the direction after switching from the given direction.
+    pub fn switch(self, direction: Direction) -> Direction {
+        match self {
+            Self::Rotate => direction.right_hand(),
+            Self::Reverse => direction.inverse(),
+        }
+    }
+}
+
+/// Entity acts as trigger for opening gates and switching [Switchable]
+/// entities on the same [Channel].
 #[derive(Component)]
 pub struct Trigger;
 
@@ -203,13 +469,46 @@ pub struct Trigger;
 #[derive(Component)]
 pub struct Volatile;
 
+/// Positions visited in turn by a [Movable::Patrol] entity, starting over
+/// after the last one.
+#[derive(Component)]
+pub struct Waypoints {
+    pub positions: Vec<Position>,
+
+    /// Index of the waypoint the entity is currently heading towards.
+    pub next: usize,
+}
+
+impl Waypoints {
+    pub fn new(positions: Vec<Position>) -> Self {
+        Self { positions, next: 0 }
+    }
+}
+
 /// Weight of an entity.
 ///
 /// Pushable entities can only be pushed by other entities of equal or more
-/// weight.
-#[derive(Clone, Component, Copy, Default, Eq, Ord, PartialEq, PartialOrd)]
-pub enum Weight {
-    #[default]
-    Light,
-    Heavy,
+/// weight. Entities without an explicit weight are [Weight::LIGHT].
+///
+/// Weights of entities on the same [Position] add up when checking for a
+/// [MinWeight]. Levels can override the weight per object type in their
+/// `[Weights]` section.
+#[derive(Clone, Component, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct Weight(pub u16);
+
+impl Weight {
+    pub const LIGHT: Self = Self(1);
+    pub const HEAVY: Self = Self(2);
+}
+
+impl Default for Weight {
+    fn default() -> Self {
+        Self::LIGHT
+    }
+}
+
+impl Sum for Weight {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        Self(iter.map(|Self(weight)| weight).sum())
+    }
 }
@@ -1,12 +1,45 @@
 use bevy::prelude::*;
 
+use crate::constants::GRID_SIZE;
+
 use super::{
     assets::GameObjectAssets,
     components::{Exit, Liquid, Massive, Player, Position, Pushable},
-    Animatable, BlocksMovement, BlocksPushes, Deadly, Direction, Explosive, Floatable, Movable,
-    ObjectType, Openable, TransformOnPush, Transporter, Trigger, Volatile, Weight,
+    Animatable, BlocksMovement, BlocksPushes, Carrier, Checkpoint, Collectible, Current, Deadly,
+    Destructible, Direction, Explosive, FillsLiquid, Floatable, LaserBeam, LaserEmitter,
+    LockedExit, MinWeight, Mirror, Movable, ObjectType, OneWay, Openable, Pit, Receiver, Rolling,
+    Switchable, TransformOnLeave, Transporter, Trigger, Volatile, Weight,
 };
 
+#[derive(Bundle)]
+pub struct BackslashMirrorBundle {
+    object_type: ObjectType,
+    massive: Massive,
+    mirror: Mirror,
+    position: Position,
+    pushable: Pushable,
+    sprite: SpriteBundle,
+    weight: Weight,
+}
+
+impl BackslashMirrorBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::BackslashMirror,
+            massive: Massive,
+            mirror: Mirror::Backslash,
+            position,
+            pushable: Pushable,
+            sprite: SpriteBundle {
+                texture: assets.backslash_mirror.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                ..Default::default()
+            },
+            weight: Weight::HEAVY,
+        }
+    }
+}
+
 #[derive(Bundle)]
 pub struct BlueBlockBundle {
     object_type: ObjectType,
@@ -29,7 +62,61 @@ impl BlueBlockBundle {
                 transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
                 ..Default::default()
             },
-            weight: Weight::Heavy,
+            weight: Weight::HEAVY,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct BombBundle {
+    object_type: ObjectType,
+    explosive: Explosive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl BombBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Bomb,
+            explosive: Explosive { blast_radius: 1 },
+            position,
+            sprite: SpriteBundle {
+                texture: assets.bomb.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct BoulderBundle {
+    object_type: ObjectType,
+    fills_liquid: FillsLiquid,
+    massive: Massive,
+    position: Position,
+    pushable: Pushable,
+    rolling: Rolling,
+    sprite: SpriteBundle,
+    weight: Weight,
+}
+
+impl BoulderBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Boulder,
+            fills_liquid: FillsLiquid(ObjectType::SunkenBlock),
+            massive: Massive,
+            position,
+            pushable: Pushable,
+            rolling: Rolling::default(),
+            sprite: SpriteBundle {
+                texture: assets.boulder.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                ..Default::default()
+            },
+            weight: Weight::HEAVY,
         }
     }
 }
@@ -87,33 +174,27 @@ impl ButtonBundle {
 }
 
 #[derive(Bundle)]
-pub struct Creature1Bundle {
+pub struct CheckpointBundle {
     object_type: ObjectType,
     atlas: TextureAtlas,
-    blocks_pushes: BlocksPushes,
-    deadly: Deadly,
-    direction: Direction,
-    movable: Movable,
+    checkpoint: Checkpoint,
     position: Position,
     sprite: SpriteBundle,
 }
 
-impl Creature1Bundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
+impl CheckpointBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
         Self {
-            object_type: ObjectType::Creature1,
+            object_type: ObjectType::Checkpoint,
             atlas: TextureAtlas {
-                layout: assets.creature1.1.clone(),
-                index: direction as usize,
+                layout: assets.checkpoint.1.clone(),
+                index: 0,
             },
-            blocks_pushes: BlocksPushes,
-            deadly: Deadly,
-            direction,
-            movable: Movable::FollowRightHand,
+            checkpoint: Checkpoint,
             position,
             sprite: SpriteBundle {
-                texture: assets.creature1.0.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
+                texture: assets.checkpoint.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
                 ..Default::default()
             },
         }
@@ -121,73 +202,90 @@ impl Creature1Bundle {
 }
 
 #[derive(Bundle)]
-pub struct ExitBundle {
+pub struct CrackedFloorBundle {
     object_type: ObjectType,
-    exit: Exit,
     position: Position,
     sprite: SpriteBundle,
+    transforms: TransformOnLeave,
 }
 
-impl ExitBundle {
+impl CrackedFloorBundle {
     pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
         Self {
-            object_type: ObjectType::Exit,
-            exit: Exit,
+            object_type: ObjectType::CrackedFloor,
             position,
             sprite: SpriteBundle {
-                texture: assets.exit.clone(),
+                texture: assets.cracked_floor.clone(),
                 transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
                 ..Default::default()
             },
+            transforms: TransformOnLeave::new(ObjectType::Pit),
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct ExplosionBundle {
+pub struct Creature1Bundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    blocks_pushes: BlocksPushes,
+    deadly: Deadly,
+    direction: Direction,
+    movable: Movable,
     position: Position,
     sprite: SpriteBundle,
-    volatile: Volatile,
 }
 
-impl ExplosionBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl Creature1Bundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
+            object_type: ObjectType::Creature1,
+            atlas: TextureAtlas {
+                layout: assets.creature1.1.clone(),
+                index: direction as usize,
+            },
+            blocks_pushes: BlocksPushes,
+            deadly: Deadly,
+            direction,
+            movable: Movable::FollowRightHand,
             position,
             sprite: SpriteBundle {
-                texture: assets.explosion.clone(),
+                texture: assets.creature1.0.clone(),
                 transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
-            volatile: Volatile,
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct GateBundle {
+pub struct Creature2Bundle {
     object_type: ObjectType,
     atlas: TextureAtlas,
-    openable: Openable,
-    massive: Massive,
+    blocks_pushes: BlocksPushes,
+    deadly: Deadly,
+    direction: Direction,
+    movable: Movable,
     position: Position,
     sprite: SpriteBundle,
 }
 
-impl GateBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl Creature2Bundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
-            object_type: ObjectType::Gate,
+            object_type: ObjectType::Creature2,
             atlas: TextureAtlas {
-                layout: assets.gate.1.clone(),
-                index: 0,
+                layout: assets.creature2.1.clone(),
+                index: direction as usize,
             },
-            massive: Massive,
-            openable: Openable,
+            blocks_pushes: BlocksPushes,
+            deadly: Deadly,
+            direction,
+            movable: Movable::FollowLeftHand,
             position,
             sprite: SpriteBundle {
-                texture: assets.gate.0.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 5.)),
+                texture: assets.creature2.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
         }
@@ -195,19 +293,32 @@ impl GateBundle {
 }
 
 #[derive(Bundle)]
-pub struct GraveBundle {
-    massive: Massive,
+pub struct Creature3Bundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    blocks_pushes: BlocksPushes,
+    deadly: Deadly,
+    direction: Direction,
+    movable: Movable,
     position: Position,
     sprite: SpriteBundle,
 }
 
-impl GraveBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl Creature3Bundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
-            massive: Massive,
+            object_type: ObjectType::Creature3,
+            atlas: TextureAtlas {
+                layout: assets.creature3.1.clone(),
+                index: direction as usize,
+            },
+            blocks_pushes: BlocksPushes,
+            deadly: Deadly,
+            direction,
+            movable: Movable::ChasePlayer,
             position,
             sprite: SpriteBundle {
-                texture: assets.grave.clone(),
+                texture: assets.creature3.0.clone(),
                 transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
@@ -216,22 +327,33 @@ impl GraveBundle {
 }
 
 #[derive(Bundle)]
-pub struct MineBundle {
+pub struct Creature4Bundle {
     object_type: ObjectType,
-    explosive: Explosive,
+    atlas: TextureAtlas,
+    blocks_pushes: BlocksPushes,
+    deadly: Deadly,
+    direction: Direction,
+    movable: Movable,
     position: Position,
     sprite: SpriteBundle,
 }
 
-impl MineBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl Creature4Bundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
-            object_type: ObjectType::Mine,
-            explosive: Explosive,
+            object_type: ObjectType::Creature4,
+            atlas: TextureAtlas {
+                layout: assets.creature4.1.clone(),
+                index: direction as usize,
+            },
+            blocks_pushes: BlocksPushes,
+            deadly: Deadly,
+            direction,
+            movable: Movable::Patrol,
             position,
             sprite: SpriteBundle {
-                texture: assets.mine.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                texture: assets.creature4.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
         }
@@ -239,139 +361,819 @@ impl MineBundle {
 }
 
 #[derive(Bundle)]
-pub struct PlayerBundle {
+pub struct Creature5Bundle {
     object_type: ObjectType,
+    atlas: TextureAtlas,
     blocks_pushes: BlocksPushes,
-    player: Player,
+    deadly: Deadly,
+    direction: Direction,
+    movable: Movable,
     position: Position,
     sprite: SpriteBundle,
-    weight: Weight,
 }
 
-impl PlayerBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl Creature5Bundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
-            object_type: ObjectType::Player,
+            object_type: ObjectType::Creature5,
+            atlas: TextureAtlas {
+                layout: assets.creature5.1.clone(),
+                index: direction as usize,
+            },
             blocks_pushes: BlocksPushes,
-            player: Player,
+            deadly: Deadly,
+            direction,
+            movable: Movable::Mirror,
             position,
             sprite: SpriteBundle {
-                texture: assets.player.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                texture: assets.creature5.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
-            weight: Weight::Heavy,
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct PurpleBlockBundle {
+pub struct DestructibleWallBundle {
     object_type: ObjectType,
+    destructible: Destructible,
     massive: Massive,
     position: Position,
-    pushable: Pushable,
     sprite: SpriteBundle,
-    transforms: TransformOnPush,
 }
 
-impl PurpleBlockBundle {
+impl DestructibleWallBundle {
     pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
         Self {
-            object_type: ObjectType::PurpleBlock,
+            object_type: ObjectType::DestructibleWall,
+            destructible: Destructible,
             massive: Massive,
             position,
-            pushable: Pushable,
             sprite: SpriteBundle {
-                texture: assets.purple_block.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                texture: assets.destructible_wall.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
                 ..Default::default()
             },
-            transforms: TransformOnPush(ObjectType::RedBlock),
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct RaftBundle {
+pub struct ExitBundle {
     object_type: ObjectType,
-    floatable: Floatable,
+    exit: Exit,
     position: Position,
-    pushable: Pushable,
     sprite: SpriteBundle,
-    weight: Weight,
 }
 
-impl RaftBundle {
+impl ExitBundle {
     pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
         Self {
-            object_type: ObjectType::Raft,
-            floatable: Floatable,
+            object_type: ObjectType::Exit,
+            exit: Exit,
             position,
-            pushable: Pushable,
             sprite: SpriteBundle {
-                texture: assets.raft.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                texture: assets.exit.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
                 ..Default::default()
             },
-            weight: Weight::Heavy,
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct RedBlockBundle {
-    object_type: ObjectType,
-    massive: Massive,
+pub struct ExplosionBundle {
     position: Position,
     sprite: SpriteBundle,
+    volatile: Volatile,
 }
 
-impl RedBlockBundle {
+impl ExplosionBundle {
     pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
         Self {
-            object_type: ObjectType::RedBlock,
-            massive: Massive,
             position,
             sprite: SpriteBundle {
-                texture: assets.red_block.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                texture: assets.explosion.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
                 ..Default::default()
             },
+            volatile: Volatile,
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct SplashBundle {
-    floatable: Floatable,
+pub struct FlowingWaterBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    current: Current,
+    direction: Direction,
+    liquid: Liquid,
     position: Position,
     sprite: SpriteBundle,
-    volatile: Volatile,
 }
 
-impl SplashBundle {
-    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+impl FlowingWaterBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
         Self {
-            floatable: Floatable,
+            object_type: ObjectType::FlowingWater,
+            atlas: TextureAtlas {
+                layout: assets.flowing_water.1.clone(),
+                index: direction as usize,
+            },
+            current: Current,
+            direction,
+            liquid: Liquid::Water,
             position,
             sprite: SpriteBundle {
-                texture: assets.splash.clone(),
-                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
+                texture: assets.flowing_water.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
                 ..Default::default()
             },
-            volatile: Volatile,
         }
     }
 }
 
 #[derive(Bundle)]
-pub struct TransporterBundle {
+pub struct GateBundle {
     object_type: ObjectType,
     atlas: TextureAtlas,
-    blocks_movement: BlocksMovement,
-    direction: Direction,
-    position: Position,
+    openable: Openable,
+    massive: Massive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl GateBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Gate,
+            atlas: TextureAtlas {
+                layout: assets.gate.1.clone(),
+                index: 0,
+            },
+            massive: Massive,
+            openable: Openable,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.gate.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 5.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct GemBundle {
+    object_type: ObjectType,
+    collectible: Collectible,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl GemBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Gem,
+            collectible: Collectible,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.gem.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct GraveBundle {
+    massive: Massive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl GraveBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            massive: Massive,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.grave.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct HeavyPressurePlateBundle {
+    object_type: ObjectType,
+    min_weight: MinWeight,
+    position: Position,
+    sprite: SpriteBundle,
+    trigger: Trigger,
+}
+
+impl HeavyPressurePlateBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::HeavyPressurePlate,
+            // Requires two heavy objects, such as the player standing on a
+            // raft that cannot be pushed any further.
+            min_weight: MinWeight(Weight(2 * Weight::HEAVY.0)),
+            position,
+            sprite: SpriteBundle {
+                texture: assets.heavy_pressure_plate.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+            trigger: Trigger,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LaserBeamBundle {
+    atlas: TextureAtlas,
+    laser_beam: LaserBeam,
+    sprite: SpriteBundle,
+}
+
+impl LaserBeamBundle {
+    /// Spawns a beam segment as child of a [LaserEmitter], at the given offset
+    /// in squares from the emitter.
+    pub fn spawn(assets: &GameObjectAssets, offset: (i16, i16), direction: Direction) -> Self {
+        let (dx, dy) = offset;
+        Self {
+            atlas: TextureAtlas {
+                layout: assets.laser_beam.1.clone(),
+                index: match direction {
+                    Direction::Up | Direction::Down => 0,
+                    Direction::Right | Direction::Left => 1,
+                },
+            },
+            laser_beam: LaserBeam,
+            sprite: SpriteBundle {
+                texture: assets.laser_beam.0.clone(),
+                transform: Transform::from_translation(Vec3::new(
+                    (dx * GRID_SIZE) as f32,
+                    -(dy * GRID_SIZE) as f32,
+                    0.5,
+                )),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LaserEmitterBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    direction: Direction,
+    laser_emitter: LaserEmitter,
+    massive: Massive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl LaserEmitterBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
+        Self {
+            object_type: ObjectType::LaserEmitter,
+            atlas: TextureAtlas {
+                layout: assets.laser_emitter.1.clone(),
+                index: 0,
+            },
+            direction,
+            laser_emitter: LaserEmitter,
+            massive: Massive,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.laser_emitter.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LavaBundle {
+    object_type: ObjectType,
+    animatable: Animatable,
+    atlas: TextureAtlas,
+    liquid: Liquid,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl LavaBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Lava,
+            animatable: Animatable { num_frames: 3 },
+            atlas: TextureAtlas {
+                layout: assets.lava.1.clone(),
+                index: 0,
+            },
+            liquid: Liquid::Lava,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.lava.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct LockedExitBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    locked_exit: LockedExit,
+    massive: Massive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl LockedExitBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::LockedExit,
+            atlas: TextureAtlas {
+                layout: assets.locked_exit.1.clone(),
+                index: 0,
+            },
+            locked_exit: LockedExit,
+            massive: Massive,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.locked_exit.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct MineBundle {
+    object_type: ObjectType,
+    explosive: Explosive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl MineBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Mine,
+            explosive: Explosive { blast_radius: 0 },
+            position,
+            sprite: SpriteBundle {
+                texture: assets.mine.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct OneWayBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    direction: Direction,
+    one_way: OneWay,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl OneWayBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
+        Self {
+            object_type: ObjectType::OneWay,
+            atlas: TextureAtlas {
+                layout: assets.one_way.1.clone(),
+                index: direction as usize,
+            },
+            direction,
+            one_way: OneWay,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.one_way.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PitBundle {
+    object_type: ObjectType,
+    pit: Pit,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl PitBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Pit,
+            pit: Pit,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.pit.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PlayerBundle {
+    object_type: ObjectType,
+    blocks_pushes: BlocksPushes,
+    player: Player,
+    position: Position,
+    sprite: SpriteBundle,
+    weight: Weight,
+}
+
+impl PlayerBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Player,
+            blocks_pushes: BlocksPushes,
+            player: Player,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.player.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                ..Default::default()
+            },
+            weight: Weight::HEAVY,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PressurePlateBundle {
+    object_type: ObjectType,
+    min_weight: MinWeight,
+    position: Position,
+    sprite: SpriteBundle,
+    trigger: Trigger,
+}
+
+impl PressurePlateBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::PressurePlate,
+            min_weight: MinWeight(Weight::HEAVY),
+            position,
+            sprite: SpriteBundle {
+                texture: assets.pressure_plate.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+            trigger: Trigger,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct PurpleBlockBundle {
+    object_type: ObjectType,
+    massive: Massive,
+    position: Position,
+    pushable: Pushable,
+    sprite: SpriteBundle,
+}
+
+impl PurpleBlockBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::PurpleBlock,
+            massive: Massive,
+            position,
+            pushable: Pushable,
+            sprite: SpriteBundle {
+                texture: assets.purple_block.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct RaftBundle {
+    object_type: ObjectType,
+    carrier: Carrier,
+    floatable: Floatable,
+    position: Position,
+    pushable: Pushable,
+    sprite: SpriteBundle,
+    weight: Weight,
+}
+
+impl RaftBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Raft,
+            carrier: Carrier::default(),
+            floatable: Floatable,
+            position,
+            pushable: Pushable,
+            sprite: SpriteBundle {
+                texture: assets.raft.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                ..Default::default()
+            },
+            weight: Weight::HEAVY,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ReceiverBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    massive: Massive,
+    position: Position,
+    receiver: Receiver,
+    sprite: SpriteBundle,
+    trigger: Trigger,
+}
+
+impl ReceiverBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Receiver,
+            atlas: TextureAtlas {
+                layout: assets.receiver.1.clone(),
+                index: 0,
+            },
+            massive: Massive,
+            position,
+            receiver: Receiver::default(),
+            sprite: SpriteBundle {
+                texture: assets.receiver.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                ..Default::default()
+            },
+            trigger: Trigger,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct RedBlockBundle {
+    object_type: ObjectType,
+    massive: Massive,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl RedBlockBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::RedBlock,
+            massive: Massive,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.red_block.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 2.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ReversingTransporterBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    blocks_movement: BlocksMovement,
+    direction: Direction,
+    position: Position,
+    sprite: SpriteBundle,
+    switchable: Switchable,
+    transporter: Transporter,
+}
+
+impl ReversingTransporterBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
+        Self {
+            object_type: ObjectType::ReversingTransporter,
+            atlas: TextureAtlas {
+                layout: assets.reversing_transporter.1.clone(),
+                index: direction as usize,
+            },
+            blocks_movement: BlocksMovement::Enabled,
+            direction,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.reversing_transporter.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+            switchable: Switchable::Reverse,
+            transporter: Transporter,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct RotatingTransporterBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    blocks_movement: BlocksMovement,
+    direction: Direction,
+    position: Position,
+    sprite: SpriteBundle,
+    switchable: Switchable,
+    transporter: Transporter,
+}
+
+impl RotatingTransporterBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, direction: Direction) -> Self {
+        Self {
+            object_type: ObjectType::RotatingTransporter,
+            atlas: TextureAtlas {
+                layout: assets.rotating_transporter.1.clone(),
+                index: direction as usize,
+            },
+            blocks_movement: BlocksMovement::Enabled,
+            direction,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.rotating_transporter.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+            switchable: Switchable::Rotate,
+            transporter: Transporter,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct ShallowWaterBundle {
+    object_type: ObjectType,
+    animatable: Animatable,
+    atlas: TextureAtlas,
+    liquid: Liquid,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl ShallowWaterBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::ShallowWater,
+            animatable: Animatable { num_frames: 3 },
+            atlas: TextureAtlas {
+                layout: assets.shallow_water.1.clone(),
+                index: 0,
+            },
+            liquid: Liquid::ShallowWater,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.shallow_water.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct SlashMirrorBundle {
+    object_type: ObjectType,
+    massive: Massive,
+    mirror: Mirror,
+    position: Position,
+    pushable: Pushable,
+    sprite: SpriteBundle,
+    weight: Weight,
+}
+
+impl SlashMirrorBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::SlashMirror,
+            massive: Massive,
+            mirror: Mirror::Slash,
+            position,
+            pushable: Pushable,
+            sprite: SpriteBundle {
+                texture: assets.slash_mirror.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 3.)),
+                ..Default::default()
+            },
+            weight: Weight::HEAVY,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct SplashBundle {
+    floatable: Floatable,
+    position: Position,
+    sprite: SpriteBundle,
+    volatile: Volatile,
+}
+
+impl SplashBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position, liquid: Liquid) -> Self {
+        let texture = match liquid {
+            Liquid::Water | Liquid::ShallowWater => assets.splash.clone(),
+            Liquid::Lava => assets.lava_splash.clone(),
+        };
+
+        Self {
+            floatable: Floatable,
+            position,
+            sprite: SpriteBundle {
+                texture,
+                transform: Transform::from_translation(Vec3::new(0., 0., 4.)),
+                ..Default::default()
+            },
+            volatile: Volatile,
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct SunkenBlockBundle {
+    object_type: ObjectType,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl SunkenBlockBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::SunkenBlock,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.sunken_block.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct SwampBundle {
+    object_type: ObjectType,
+    animatable: Animatable,
+    atlas: TextureAtlas,
+    fills_liquid: FillsLiquid,
+    liquid: Liquid,
+    position: Position,
+    sprite: SpriteBundle,
+}
+
+impl SwampBundle {
+    pub fn spawn(assets: &GameObjectAssets, position: Position) -> Self {
+        Self {
+            object_type: ObjectType::Swamp,
+            animatable: Animatable { num_frames: 3 },
+            atlas: TextureAtlas {
+                layout: assets.swamp.1.clone(),
+                index: 0,
+            },
+            fills_liquid: FillsLiquid(ObjectType::SunkenBlock),
+            liquid: Liquid::Water,
+            position,
+            sprite: SpriteBundle {
+                texture: assets.swamp.0.clone(),
+                transform: Transform::from_translation(Vec3::new(0., 0., 1.)),
+                ..Default::default()
+            },
+        }
+    }
+}
+
+#[derive(Bundle)]
+pub struct TransporterBundle {
+    object_type: ObjectType,
+    atlas: TextureAtlas,
+    blocks_movement: BlocksMovement,
+    direction: Direction,
+    position: Position,
     sprite: SpriteBundle,
     transporter: Transporter,
 }
@@ -382,7 +1184,7 @@ impl TransporterBundle {
             object_type: ObjectType::Transporter,
             atlas: TextureAtlas {
                 layout: assets.transporter.1.clone(),
-                index: 0,
+                index: direction as usize,
             },
             blocks_movement: BlocksMovement::Enabled,
             direction,
@@ -416,7 +1218,7 @@ impl WaterBundle {
                 layout: assets.water.1.clone(),
                 index: 0,
             },
-            liquid: Liquid,
+            liquid: Liquid::Water,
             position,
             sprite: SpriteBundle {
                 texture: assets.water.0.clone(),
@@ -9,26 +9,57 @@ use super::{
     assets::GameObjectAssets,
     components::Position,
     object_bundles::{
-        BlueBlockBundle, BouncingBallBundle, Creature1Bundle, ExitBundle, PlayerBundle, RaftBundle,
-        RedBlockBundle, WaterBundle,
+        BackslashMirrorBundle, BlueBlockBundle, BombBundle, BoulderBundle, BouncingBallBundle,
+        CheckpointBundle, CrackedFloorBundle, Creature1Bundle, Creature2Bundle, Creature3Bundle,
+        Creature4Bundle, Creature5Bundle, DestructibleWallBundle, ExitBundle, FlowingWaterBundle,
+        GemBundle, LockedExitBundle, PitBundle, PlayerBundle, RaftBundle, RedBlockBundle,
+        WaterBundle,
     },
-    ButtonBundle, Direction, GateBundle, MineBundle, PurpleBlockBundle, TransporterBundle,
-    YellowBlockBundle,
+    ButtonBundle, Direction, GateBundle, HeavyPressurePlateBundle, LaserEmitterBundle, LavaBundle,
+    MineBundle, OneWayBundle, PressurePlateBundle, PurpleBlockBundle, ReceiverBundle,
+    ReversingTransporterBundle, RotatingTransporterBundle, ShallowWaterBundle, SlashMirrorBundle,
+    SunkenBlockBundle, SwampBundle, TransporterBundle, YellowBlockBundle,
 };
 
 #[derive(Clone, Component, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
 pub enum ObjectType {
+    BackslashMirror,
     BlueBlock,
+    Bomb,
+    Boulder,
     BouncingBall,
     Button,
+    Checkpoint,
+    CrackedFloor,
     Creature1,
+    Creature2,
+    Creature3,
+    Creature4,
+    Creature5,
+    DestructibleWall,
     Exit,
+    FlowingWater,
     Gate,
+    Gem,
+    HeavyPressurePlate,
+    LaserEmitter,
+    Lava,
+    LockedExit,
     Mine,
+    OneWay,
+    Pit,
     Player,
+    PressurePlate,
     PurpleBlock,
     Raft,
+    Receiver,
     RedBlock,
+    ReversingTransporter,
+    RotatingTransporter,
+    ShallowWater,
+    SlashMirror,
+    SunkenBlock,
+    Swamp,
     Transporter,
     Water,
     YellowBlock,
@@ -37,17 +68,43 @@ pub enum ObjectType {
 impl Display for ObjectType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         f.write_str(match self {
+            Self::BackslashMirror => "BackslashMirror",
             Self::BlueBlock => "BlueBlock",
+            Self::Bomb => "Bomb",
+            Self::Boulder => "Boulder",
             Self::BouncingBall => "BouncingBall",
             Self::Button => "Button",
+            Self::Checkpoint => "Checkpoint",
+            Self::CrackedFloor => "CrackedFloor",
             Self::Creature1 => "Creature1",
+            Self::Creature2 => "Creature2",
+            Self::Creature3 => "Creature3",
+            Self::Creature4 => "Creature4",
+            Self::Creature5 => "Creature5",
+            Self::DestructibleWall => "DestructibleWall",
             Self::Exit => "Exit",
+            Self::FlowingWater => "FlowingWater",
             Self::Gate => "Gate",
+            Self::Gem => "Gem",
+            Self::HeavyPressurePlate => "HeavyPressurePlate",
+            Self::LaserEmitter => "LaserEmitter",
+            Self::Lava => "Lava",
+            Self::LockedExit => "LockedExit",
             Self::Mine => "Mine",
+            Self::OneWay => "OneWay",
+            Self::Pit => "Pit",
             Self::Player => "Player",
+            Self::PressurePlate => "PressurePlate",
             Self::PurpleBlock => "PurpleBlock",
             Self::Raft => "Raft",
+            Self::Receiver => "Receiver",
             Self::RedBlock => "RedBlock",
+            Self::ReversingTransporter => "ReversingTransporter",
+            Self::RotatingTransporter => "RotatingTransporter",
+            Self::ShallowWater => "ShallowWater",
+            Self::SlashMirror => "SlashMirror",
+            Self::SunkenBlock => "SunkenBlock",
+            Self::Swamp => "Swamp",
             Self::Transporter => "Transporter",
             Self::Water => "Water",
             Self::YellowBlock => "YellowBlock",
@@ -60,17 +117,43 @@ impl FromStr for ObjectType {
 
     fn from_str(object_type: &str) -> Result<Self, Self::Err> {
         match object_type {
+            "BackslashMirror" => Ok(Self::BackslashMirror),
             "BlueBlock" => Ok(Self::BlueBlock),
+            "Bomb" => Ok(Self::Bomb),
+            "Boulder" => Ok(Self::Boulder),
             "BouncingBall" => Ok(Self::BouncingBall),
             "Button" => Ok(Self::Button),
+            "Checkpoint" => Ok(Self::Checkpoint),
+            "CrackedFloor" => Ok(Self::CrackedFloor),
             "Creature1" => Ok(Self::Creature1),
+            "Creature2" => Ok(Self::Creature2),
+            "Creature3" => Ok(Self::Creature3),
+            "Creature4" => Ok(Self::Creature4),
+            "Creature5" => Ok(Self::Creature5),
+            "DestructibleWall" => Ok(Self::DestructibleWall),
             "Exit" => Ok(Self::Exit),
+            "FlowingWater" => Ok(Self::FlowingWater),
             "Gate" => Ok(Self::Gate),
+            "Gem" => Ok(Self::Gem),
+            "HeavyPressurePlate" => Ok(Self::HeavyPressurePlate),
+            "LaserEmitter" => Ok(Self::LaserEmitter),
+            "Lava" => Ok(Self::Lava),
+            "LockedExit" => Ok(Self::LockedExit),
             "Mine" => Ok(Self::Mine),
+            "OneWay" => Ok(Self::OneWay),
+            "Pit" => Ok(Self::Pit),
             "Player" => Ok(Self::Player),
+            "PressurePlate" => Ok(Self::PressurePlate),
             "PurpleBlock" => Ok(Self::PurpleBlock),
             "Raft" => Ok(Self::Raft),
+            "Receiver" => Ok(Self::Receiver),
             "RedBlock" => Ok(Self::RedBlock),
+            "ReversingTransporter" => Ok(Self::ReversingTransporter),
+            "RotatingTransporter" => Ok(Self::RotatingTransporter),
+            "ShallowWater" => Ok(Self::ShallowWater),
+            "SlashMirror" => Ok(Self::SlashMirror),
+            "SunkenBlock" => Ok(Self::SunkenBlock),
+            "Swamp" => Ok(Self::Swamp),
             "Transporter" => Ok(Self::Transporter),
             "Water" => Ok(Self::Water),
             "YellowBlock" => Ok(Self::YellowBlock),
@@ -79,6 +162,92 @@ impl FromStr for ObjectType {
     }
 }
 
+/// Layer of the board an object is placed on in the editor.
+///
+/// Objects can be stacked on top of objects in other layers, while placing an
+/// object replaces whatever was on the same layer.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum Layer {
+    /// Tiles that other objects stand on, such as liquids, triggers and exits.
+    Floor,
+
+    /// Small objects lying on the floor, and rafts floating on it.
+    Item,
+
+    /// Blocks, walls, players and creatures.
+    Object,
+}
+
+impl Layer {
+    pub const ALL: [Self; 3] = [Self::Floor, Self::Item, Self::Object];
+}
+
+impl ObjectType {
+    pub fn layer(self) -> Layer {
+        match self {
+            Self::Button
+            | Self::Checkpoint
+            | Self::CrackedFloor
+            | Self::Exit
+            | Self::FlowingWater
+            | Self::HeavyPressurePlate
+            | Self::Lava
+            | Self::LockedExit
+            | Self::OneWay
+            | Self::Pit
+            | Self::PressurePlate
+            | Self::ReversingTransporter
+            | Self::RotatingTransporter
+            | Self::ShallowWater
+            | Self::SunkenBlock
+            | Self::Swamp
+            | Self::Transporter
+            | Self::Water => Layer::Floor,
+            Self::Bomb | Self::Gem | Self::Mine | Self::Raft => Layer::Item,
+            Self::BackslashMirror
+            | Self::BlueBlock
+            | Self::Boulder
+            | Self::BouncingBall
+            | Self::Creature1
+            | Self::Creature2
+            | Self::Creature3
+            | Self::Creature4
+            | Self::Creature5
+            | Self::DestructibleWall
+            | Self::Gate
+            | Self::LaserEmitter
+            | Self::Player
+            | Self::PurpleBlock
+            | Self::Receiver
+            | Self::RedBlock
+            | Self::SlashMirror
+            | Self::YellowBlock => Layer::Object,
+        }
+    }
+
+    /// Returns whether objects of this type patrol along waypoints.
+    pub fn has_waypoints(self) -> bool {
+        self == Self::Creature4
+    }
+
+    /// Returns whether objects of this type are wired to other objects through
+    /// a [Channel].
+    ///
+    /// [Channel]: super::Channel
+    pub fn has_channel(self) -> bool {
+        matches!(
+            self,
+            Self::Button
+                | Self::Gate
+                | Self::HeavyPressurePlate
+                | Self::PressurePlate
+                | Self::Receiver
+                | Self::ReversingTransporter
+                | Self::RotatingTransporter
+        )
+    }
+}
+
 pub fn spawn_object_of_type<'a>(
     cb: &'a mut ChildBuilder,
     assets: &GameObjectAssets,
@@ -87,19 +256,55 @@ pub fn spawn_object_of_type<'a>(
     direction: Direction,
 ) -> EntityCommands<'a> {
     match object_type {
+        ObjectType::BackslashMirror => cb.spawn(BackslashMirrorBundle::spawn(assets, position)),
         ObjectType::BlueBlock => cb.spawn(BlueBlockBundle::spawn(assets, position)),
+        ObjectType::Bomb => cb.spawn(BombBundle::spawn(assets, position)),
+        ObjectType::Boulder => cb.spawn(BoulderBundle::spawn(assets, position)),
         ObjectType::BouncingBall => {
             cb.spawn(BouncingBallBundle::spawn(assets, position, direction))
         }
         ObjectType::Button => cb.spawn(ButtonBundle::spawn(assets, position)),
+        ObjectType::Checkpoint => cb.spawn(CheckpointBundle::spawn(assets, position)),
+        ObjectType::CrackedFloor => cb.spawn(CrackedFloorBundle::spawn(assets, position)),
         ObjectType::Creature1 => cb.spawn(Creature1Bundle::spawn(assets, position, direction)),
+        ObjectType::Creature2 => cb.spawn(Creature2Bundle::spawn(assets, position, direction)),
+        ObjectType::Creature3 => cb.spawn(Creature3Bundle::spawn(assets, position, direction)),
+        ObjectType::Creature4 => cb.spawn(Creature4Bundle::spawn(assets, position, direction)),
+        ObjectType::Creature5 => cb.spawn(Creature5Bundle::spawn(assets, position, direction)),
+        ObjectType::DestructibleWall => cb.spawn(DestructibleWallBundle::spawn(assets, position)),
         ObjectType::Exit => cb.spawn(ExitBundle::spawn(assets, position)),
+        ObjectType::FlowingWater => {
+            cb.spawn(FlowingWaterBundle::spawn(assets, position, direction))
+        }
         ObjectType::Gate => cb.spawn(GateBundle::spawn(assets, position)),
+        ObjectType::HeavyPressurePlate => {
+            cb.spawn(HeavyPressurePlateBundle::spawn(assets, position))
+        }
+        ObjectType::Gem => cb.spawn(GemBundle::spawn(assets, position)),
+        ObjectType::LaserEmitter => {
+            cb.spawn(LaserEmitterBundle::spawn(assets, position, direction))
+        }
+        ObjectType::Lava => cb.spawn(LavaBundle::spawn(assets, position)),
+        ObjectType::LockedExit => cb.spawn(LockedExitBundle::spawn(assets, position)),
         ObjectType::Mine => cb.spawn(MineBundle::spawn(assets, position)),
+        ObjectType::OneWay => cb.spawn(OneWayBundle::spawn(assets, position, direction)),
+        ObjectType::Pit => cb.spawn(PitBundle::spawn(assets, position)),
         ObjectType::Player => cb.spawn(PlayerBundle::spawn(assets, position)),
+        ObjectType::PressurePlate => cb.spawn(PressurePlateBundle::spawn(assets, position)),
         ObjectType::PurpleBlock => cb.spawn(PurpleBlockBundle::spawn(assets, position)),
         ObjectType::Raft => cb.spawn(RaftBundle::spawn(assets, position)),
+        ObjectType::Receiver => cb.spawn(ReceiverBundle::spawn(assets, position)),
         ObjectType::RedBlock => cb.spawn(RedBlockBundle::spawn(assets, position)),
+        ObjectType::ReversingTransporter => cb.spawn(ReversingTransporterBundle::spawn(
+            assets, position, direction,
+        )),
+        ObjectType::RotatingTransporter => cb.spawn(RotatingTransporterBundle::spawn(
+            assets, position, direction,
+        )),
+        ObjectType::ShallowWater => cb.spawn(ShallowWaterBundle::spawn(assets, position)),
+        ObjectType::SlashMirror => cb.spawn(SlashMirrorBundle::spawn(assets, position)),
+        ObjectType::SunkenBlock => cb.spawn(SunkenBlockBundle::spawn(assets, position)),
+        ObjectType::Swamp => cb.spawn(SwampBundle::spawn(assets, position)),
         ObjectType::Transporter => cb.spawn(TransporterBundle::spawn(assets, position, direction)),
         ObjectType::Water => cb.spawn(WaterBundle::spawn(assets, position)),
         ObjectType::YellowBlock => cb.spawn(YellowBlockBundle::spawn(assets, position)),
@@ -0,0 +1,143 @@
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
+
+use bevy::prelude::*;
+
+use crate::errors::InvalidTransformationRule;
+
+use super::ObjectType;
+
+/// Decides when an entity transforms according to a [TransformationRule].
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub enum TransformationTrigger {
+    /// The entity gets pushed.
+    Push,
+
+    /// The entity enters a liquid. It transforms instead of sinking.
+    Liquid,
+
+    /// Any trigger gets pressed.
+    Trigger,
+
+    /// The given number of movement ticks passed since the entity spawned.
+    Ticks(u16),
+
+    /// The entity shares its position with an entity of the given type.
+    Contact(ObjectType),
+}
+
+impl Display for TransformationTrigger {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Push => f.write_str("Push"),
+            Self::Liquid => f.write_str("Liquid"),
+            Self::Trigger => f.write_str("Trigger"),
+            Self::Ticks(ticks) => f.write_fmt(format_args!("Ticks({ticks})")),
+            Self::Contact(object_type) => f.write_fmt(format_args!("Contact({object_type})")),
+        }
+    }
+}
+
+impl FromStr for TransformationTrigger {
+    type Err = InvalidTransformationRule;
+
+    fn from_str(trigger: &str) -> Result<Self, Self::Err> {
+        let argument = |name: &str| {
+            trigger
+                .strip_prefix(name)
+                .and_then(|rest| rest.strip_prefix('('))
+                .and_then(|rest| rest.strip_suffix(')'))
+        };
+
+        match trigger {
+            "Push" => Ok(Self::Push),
+            "Liquid" => Ok(Self::Liquid),
+            "Trigger" => Ok(Self::Trigger),
+            _ => {
+                if let Some(ticks) = argument("Ticks") {
+                    ticks
+                        .parse()
+                        .map(Self::Ticks)
+                        .map_err(|_| InvalidTransformationRule)
+                } else if let Some(object_type) = argument("Contact") {
+                    ObjectType::from_str(object_type)
+                        .map(Self::Contact)
+                        .map_err(|_| InvalidTransformationRule)
+                } else {
+                    Err(InvalidTransformationRule)
+                }
+            }
+        }
+    }
+}
+
+/// Rule for transforming an entity into another of the given type.
+///
+/// Rules are written as `<trigger>:<object type>`, for instance
+/// `Push:RedBlock` or `Contact(Player):Pit`.
+#[derive(Clone, Copy, Debug, Eq, Ord, PartialEq, PartialOrd)]
+pub struct TransformationRule {
+    pub trigger: TransformationTrigger,
+    pub object_type: ObjectType,
+}
+
+impl Display for TransformationRule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_fmt(format_args!("{}:{}", self.trigger, self.object_type))
+    }
+}
+
+impl FromStr for TransformationRule {
+    type Err = InvalidTransformationRule;
+
+    fn from_str(rule: &str) -> Result<Self, Self::Err> {
+        let (trigger, object_type) = rule.split_once(':').ok_or(InvalidTransformationRule)?;
+
+        Ok(Self {
+            trigger: trigger.parse()?,
+            object_type: object_type.parse().map_err(|_| InvalidTransformationRule)?,
+        })
+    }
+}
+
+/// The transformation rules for all object types in the current level.
+///
+/// Every object type has its own default rules, which may be overridden by
+/// the level.
+#[derive(Clone, Debug, Default, Resource)]
+pub struct TransformationRules {
+    /// Rules that replace the default rules of their object type.
+    pub overrides: BTreeMap<ObjectType, Vec<TransformationRule>>,
+}
+
+impl TransformationRules {
+    /// Returns the rules that apply to the given object type.
+    pub fn get(&self, object_type: ObjectType) -> &[TransformationRule] {
+        match self.overrides.get(&object_type) {
+            Some(rules) => rules,
+            None => default_rules(object_type),
+        }
+    }
+
+    /// Returns the type the given object type should transform into, if any
+    /// of its rules matches the given predicate.
+    pub fn find(
+        &self,
+        object_type: ObjectType,
+        predicate: impl Fn(TransformationTrigger) -> bool,
+    ) -> Option<ObjectType> {
+        self.get(object_type)
+            .iter()
+            .find(|rule| predicate(rule.trigger))
+            .map(|rule| rule.object_type)
+    }
+}
+
+fn default_rules(object_type: ObjectType) -> &'static [TransformationRule] {
+    match object_type {
+        ObjectType::PurpleBlock => &[TransformationRule {
+            trigger: TransformationTrigger::Push,
+            object_type: ObjectType::RedBlock,
+        }],
+        _ => &[],
+    }
+}
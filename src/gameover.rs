@@ -1,10 +1,28 @@
 use bevy::prelude::*;
 
-use crate::{constants::*, editor::Editor, fonts::Fonts, Player};
+use crate::{
+    checkpoint::CheckpointSnapshot, constants::*, editor::Editor, fonts::Fonts,
+    level::WinCondition, Player,
+};
+
+const GAME_OVER_TEXT: &str = "Game Over\n\nPress Enter to try again";
+const GAME_OVER_WITH_CHECKPOINT_TEXT: &str =
+    "Game Over\n\nPress Enter to try again\nPress C to respawn at checkpoint";
 
 #[derive(Component)]
 pub struct GameOver;
 
+#[derive(Component)]
+pub struct GameOverText;
+
+#[derive(Default, Resource)]
+pub struct GameOverState {
+    pub is_game_over: bool,
+
+    /// Number of players the level started with.
+    num_players: usize,
+}
+
 pub fn setup_gameover(commands: &mut Commands, fonts: &Fonts) {
     commands
         .spawn((
@@ -12,8 +30,8 @@ pub fn setup_gameover(commands: &mut Commands, fonts: &Fonts) {
             NodeBundle {
                 style: Style {
                     display: Display::None,
-                    width: Val::Px(300.),
-                    height: Val::Px(80.),
+                    width: Val::Px(340.),
+                    padding: UiRect::all(Val::Px(16.)),
                     border: UiRect::all(Val::Px(2.)),
                     margin: UiRect::all(Val::Auto),
                     position_type: PositionType::Absolute,
@@ -26,37 +44,69 @@ pub fn setup_gameover(commands: &mut Commands, fonts: &Fonts) {
             },
         ))
         .with_children(|cb| {
-            cb.spawn(TextBundle {
-                text: Text::from_section(
-                    "Game Over\n\nPress Enter to try again",
-                    TextStyle {
-                        font: fonts.poppins_light.clone(),
-                        font_size: 20.,
-                        color: WHITE,
+            cb.spawn((
+                GameOverText,
+                TextBundle {
+                    text: Text::from_section(
+                        GAME_OVER_TEXT,
+                        TextStyle {
+                            font: fonts.poppins_light.clone(),
+                            font_size: 20.,
+                            color: WHITE,
+                        },
+                    )
+                    .with_justify(JustifyText::Center),
+                    style: Style {
+                        margin: UiRect::all(Val::Auto),
+                        ..Default::default()
                     },
-                )
-                .with_justify(JustifyText::Center),
-                style: Style {
-                    margin: UiRect::all(Val::Auto),
                     ..Default::default()
                 },
-                ..Default::default()
-            });
+            ));
         });
 }
 
+#[allow(clippy::too_many_arguments)]
 pub fn check_for_game_over(
     mut game_over_query: Query<&mut Style, With<GameOver>>,
+    mut game_over_text_query: Query<&mut Text, With<GameOverText>>,
+    mut game_over_state: ResMut<GameOverState>,
+    added_player_query: Query<(), Added<Player>>,
     editor_query: Query<Entity, With<Editor>>,
     player_query: Query<Entity, With<Player>>,
+    checkpoint_snapshot: Res<CheckpointSnapshot>,
+    win_condition: Res<WinCondition>,
 ) {
     let mut game_over_style = game_over_query.get_single_mut().unwrap();
 
-    if player_query.get_single().is_ok() || editor_query.get_single().is_ok() {
+    let editor_open = editor_query.get_single().is_ok();
+    let num_players = player_query.iter().count();
+    if editor_open || !added_player_query.is_empty() {
+        game_over_state.num_players = num_players;
+    }
+
+    // When all players need to reach an exit, losing any of them means the
+    // level can no longer be completed.
+    let is_game_over = !editor_open
+        && (num_players == 0
+            || (*win_condition == WinCondition::AllPlayersOnExits
+                && num_players < game_over_state.num_players));
+    if game_over_state.is_game_over != is_game_over {
+        game_over_state.is_game_over = is_game_over;
+    }
+
+    if !is_game_over {
         if game_over_style.display != Display::None {
             game_over_style.display = Display::None;
         }
     } else if game_over_style.display != Display::Flex {
         game_over_style.display = Display::Flex;
+
+        let mut text = game_over_text_query.get_single_mut().unwrap();
+        text.sections[0].value = if checkpoint_snapshot.is_available() {
+            GAME_OVER_WITH_CHECKPOINT_TEXT.to_owned()
+        } else {
+            GAME_OVER_TEXT.to_owned()
+        };
     }
 }
@@ -0,0 +1,89 @@
+use bevy::prelude::*;
+
+use crate::{constants::*, editor::Editor, fonts::Fonts, Collectible};
+
+#[derive(Component)]
+pub struct Hud;
+
+#[derive(Component)]
+pub struct HudText;
+
+#[derive(Default, Resource)]
+pub struct CollectedItems {
+    pub num_collected: usize,
+
+    /// Number of collectibles the level started with.
+    num_total: usize,
+}
+
+pub fn setup_hud(commands: &mut Commands, fonts: &Fonts) {
+    commands
+        .spawn((
+            Hud,
+            NodeBundle {
+                style: Style {
+                    display: Display::None,
+                    padding: UiRect::axes(Val::Px(12.), Val::Px(6.)),
+                    border: UiRect::all(Val::Px(2.)),
+                    top: Val::Px(10.),
+                    left: Val::Px(10.),
+                    position_type: PositionType::Absolute,
+                    ..Default::default()
+                },
+                background_color: GRAY_BACKGROUND.into(),
+                border_color: LIGHT_GRAY.into(),
+                z_index: ZIndex::Global(50),
+                ..Default::default()
+            },
+        ))
+        .with_children(|cb| {
+            cb.spawn((
+                HudText,
+                TextBundle::from_section(
+                    "",
+                    TextStyle {
+                        font: fonts.poppins_light.clone(),
+                        font_size: 20.,
+                        color: WHITE,
+                    },
+                ),
+            ));
+        });
+}
+
+pub fn update_hud(
+    mut hud_query: Query<&mut Style, With<Hud>>,
+    mut text_query: Query<&mut Text, With<HudText>>,
+    mut collected_items: ResMut<CollectedItems>,
+    added_collectible_query: Query<(), Added<Collectible>>,
+    collectible_query: Query<(), With<Collectible>>,
+    editor_query: Query<Entity, With<Editor>>,
+) {
+    let mut hud_style = hud_query.get_single_mut().unwrap();
+
+    let editor_open = editor_query.get_single().is_ok();
+    if editor_open {
+        collected_items.num_collected = 0;
+    }
+    if editor_open || !added_collectible_query.is_empty() {
+        collected_items.num_total =
+            collected_items.num_collected + collectible_query.iter().count();
+    }
+
+    if collected_items.num_total == 0 {
+        if hud_style.display != Display::None {
+            hud_style.display = Display::None;
+        }
+        return;
+    } else if hud_style.display != Display::Flex {
+        hud_style.display = Display::Flex;
+    }
+
+    if collected_items.is_changed() {
+        let mut text = text_query.get_single_mut().unwrap();
+        text.sections[0].value = format!(
+            "Gems: {}/{}",
+            collected_items.num_collected, collected_items.num_total
+        );
+    }
+}
@@ -1,33 +1,19 @@
 use std::{
-    cmp::Ordering,
     collections::{btree_map::Entry, BTreeMap},
+    fmt::Display,
     str::FromStr,
 };
 
 use bevy::prelude::Resource;
 
-use crate::game_object::{Direction, ObjectType, Position};
-
-pub const LEVELS: &[&str] = &[
-    include_str!("../assets/levels/level001"),
-    include_str!("../assets/levels/level002"),
-    include_str!("../assets/levels/level003"),
-    include_str!("../assets/levels/level004"),
-    include_str!("../assets/levels/level005"),
-    include_str!("../assets/levels/level006"),
-    include_str!("../assets/levels/level007"),
-    include_str!("../assets/levels/level008"),
-    include_str!("../assets/levels/level009"),
-    include_str!("../assets/levels/level010"),
-    include_str!("../assets/levels/level011"),
-    include_str!("../assets/levels/level012"),
-    include_str!("../assets/levels/level013"),
-    include_str!("../assets/levels/level014"),
-    include_str!("../assets/levels/level015"),
-    include_str!("../assets/levels/level016"),
-    include_str!("../assets/levels/level017"),
-    include_str!("../assets/levels/level018"),
-];
+use crate::{
+    errors::UnknownWinCondition,
+    game_object::{Channel, Direction, ObjectType, Position, TransformationRule, Weight},
+};
+
+/// Levels that are built into the game: All level files in `assets/levels`,
+/// in order. The list is generated by the build script.
+pub const LEVELS: &[&str] = include!(concat!(env!("OUT_DIR"), "/levels.rs"));
 
 #[derive(Clone, Copy, Resource)]
 pub struct Dimensions {
@@ -35,6 +21,13 @@ pub struct Dimensions {
     pub height: i16,
 }
 
+impl Dimensions {
+    /// Returns whether the given position lies within the level.
+    pub fn contains(&self, position: Position) -> bool {
+        position.x >= 1 && position.x <= self.width && position.y >= 1 && position.y <= self.height
+    }
+}
+
 impl Default for Dimensions {
     fn default() -> Self {
         Self {
@@ -44,21 +37,83 @@ impl Default for Dimensions {
     }
 }
 
+/// Decides when a level is completed.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq, Resource)]
+pub enum WinCondition {
+    /// The level is completed as soon as any of the players reaches an exit.
+    #[default]
+    AnyPlayerOnExit,
+
+    /// The level is completed when all players are standing on an exit.
+    AllPlayersOnExits,
+}
+
+/// Weights of object types that are overridden by the current level.
+///
+/// For object types with a [MinWeight], the minimum weight is overridden
+/// instead.
+///
+/// [MinWeight]: crate::game_object::MinWeight
+#[derive(Clone, Debug, Default, Resource)]
+pub struct WeightOverrides(pub BTreeMap<ObjectType, Weight>);
+
+impl Display for WinCondition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::AnyPlayerOnExit => "AnyPlayerOnExit",
+            Self::AllPlayersOnExits => "AllPlayersOnExits",
+        })
+    }
+}
+
+impl FromStr for WinCondition {
+    type Err = UnknownWinCondition;
+
+    fn from_str(win_condition: &str) -> Result<Self, Self::Err> {
+        match win_condition {
+            "AnyPlayerOnExit" => Ok(Self::AnyPlayerOnExit),
+            "AllPlayersOnExits" => Ok(Self::AllPlayersOnExits),
+            _ => Err(UnknownWinCondition),
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct InitialPositionAndDirection {
     pub position: Position,
     pub direction: Option<Direction>,
+
+    /// Waypoints for patrolling objects. Empty for all other objects.
+    pub waypoints: Vec<Position>,
+
+    /// Channel for objects that are wired to triggers. The default channel
+    /// for all other objects.
+    pub channel: Channel,
 }
 
 pub struct Level {
     pub dimensions: Dimensions,
     pub objects: BTreeMap<ObjectType, Vec<InitialPositionAndDirection>>,
+
+    /// Transformation rules that override the defaults of their object type.
+    pub transformations: BTreeMap<ObjectType, Vec<TransformationRule>>,
+
+    /// Weights that override the defaults of their object type.
+    pub weights: BTreeMap<ObjectType, Weight>,
+
+    pub win_condition: WinCondition,
 }
 
 impl Level {
     pub fn load(content: &str) -> Self {
         let mut dimensions = Dimensions::default();
         let mut direction = None;
+        let mut waypoints = Vec::new();
+        let mut channel = Channel::default();
         let mut objects: BTreeMap<ObjectType, Vec<InitialPositionAndDirection>> = BTreeMap::new();
+        let mut transformations = BTreeMap::new();
+        let mut weights = BTreeMap::new();
+        let mut win_condition = WinCondition::default();
 
         let mut section_name = None;
         for line in content.lines() {
@@ -66,6 +121,8 @@ impl Level {
 
             if line.starts_with('[') && line.ends_with(']') {
                 direction = None;
+                waypoints = Vec::new();
+                channel = Channel::default();
                 section_name = Some(&line[1..line.len() - 1]);
                 continue;
             }
@@ -79,6 +136,14 @@ impl Level {
             };
 
             if section_name == "General" {
+                if key == "WinCondition" {
+                    match WinCondition::from_str(value) {
+                        Ok(value) => win_condition = value,
+                        Err(_) => println!("Unknown win condition: {value}"),
+                    }
+                    continue;
+                }
+
                 match (key, value.parse()) {
                     ("Width", Ok(value)) => dimensions.width = value,
                     ("Height", Ok(value)) => dimensions.height = value,
@@ -88,6 +153,42 @@ impl Level {
                 continue;
             }
 
+            if section_name == "Transformations" {
+                let Ok(object_type) = ObjectType::from_str(key) else {
+                    println!("Unknown object type: {key}");
+                    continue;
+                };
+
+                let rules = value
+                    .split(';')
+                    .filter(|rule| !rule.is_empty())
+                    .filter_map(|rule| match TransformationRule::from_str(rule) {
+                        Ok(rule) => Some(rule),
+                        Err(_) => {
+                            println!("Invalid transformation rule: {rule}");
+                            None
+                        }
+                    })
+                    .collect();
+                transformations.insert(object_type, rules);
+                continue;
+            }
+
+            if section_name == "Weights" {
+                let Ok(object_type) = ObjectType::from_str(key) else {
+                    println!("Unknown object type: {key}");
+                    continue;
+                };
+
+                match value.parse() {
+                    Ok(value) => {
+                        weights.insert(object_type, Weight(value));
+                    }
+                    Err(error) => println!("Invalid weight {value}: {error}"),
+                }
+                continue;
+            }
+
             let object_type = match ObjectType::from_str(section_name) {
                 Ok(object_type) => object_type,
                 Err(_) => {
@@ -97,20 +198,13 @@ impl Level {
             };
 
             if key == "Position" {
-                let positions: Vec<InitialPositionAndDirection> = value
-                    .split(';')
-                    .filter_map(|location| match location.split_once(',') {
-                        Some((x, y)) => match (x.parse(), y.parse()) {
-                            (Ok(x), Ok(y)) => Some(InitialPositionAndDirection {
-                                position: Position { x, y },
-                                direction,
-                            }),
-                            _ => {
-                                println!("Invalid location ({x},{y})");
-                                None
-                            }
-                        },
-                        _ => None,
+                let positions: Vec<InitialPositionAndDirection> = parse_positions(value)
+                    .into_iter()
+                    .map(|position| InitialPositionAndDirection {
+                        position,
+                        direction,
+                        waypoints: waypoints.clone(),
+                        channel,
                     })
                     .collect();
 
@@ -130,6 +224,13 @@ impl Level {
                         println!("Unknown direction: {value}");
                     }
                 }
+            } else if key == "Waypoints" {
+                waypoints = parse_positions(value);
+            } else if key == "Channel" {
+                match value.parse() {
+                    Ok(value) => channel = Channel(value),
+                    Err(error) => println!("Invalid channel {value}: {error}"),
+                }
             } else {
                 println!("Unknown key: {key}");
             }
@@ -138,6 +239,9 @@ impl Level {
         Self {
             dimensions,
             objects,
+            transformations,
+            weights,
+            win_condition,
         }
     }
 
@@ -145,19 +249,44 @@ impl Level {
         let Dimensions { width, height } = self.dimensions;
 
         let mut content = format!("[General]\nWidth={width}\nHeight={height}\n");
+        if self.win_condition != WinCondition::default() {
+            content.push_str(&format!("WinCondition={}\n", self.win_condition));
+        }
+
+        if !self.transformations.is_empty() {
+            content.push_str("\n[Transformations]\n");
+            for (object_type, rules) in self.transformations {
+                let rules: Vec<_> = rules.iter().map(ToString::to_string).collect();
+                content.push_str(&format!("{object_type}={}\n", rules.join(";")));
+            }
+        }
+
+        if !self.weights.is_empty() {
+            content.push_str("\n[Weights]\n");
+            for (object_type, Weight(weight)) in self.weights {
+                content.push_str(&format!("{object_type}={weight}\n"));
+            }
+        }
 
         for (object_type, mut positions) in self.objects {
             content.push_str(&format!("\n[{object_type}]\n"));
 
-            positions.sort_unstable_by(|a, b| match a.direction.cmp(&b.direction) {
-                Ordering::Equal => a.position.cmp(&b.position),
-                ordering => ordering,
+            positions.sort_unstable_by(|a, b| {
+                a.direction
+                    .cmp(&b.direction)
+                    .then_with(|| a.waypoints.cmp(&b.waypoints))
+                    .then_with(|| a.channel.cmp(&b.channel))
+                    .then_with(|| a.position.cmp(&b.position))
             });
 
             let mut current_direction = Direction::default();
+            let mut current_waypoints = Vec::new();
+            let mut current_channel = Channel::default();
             for InitialPositionAndDirection {
                 position,
                 direction,
+                waypoints,
+                channel,
             } in positions
             {
                 if let Some(direction) = direction {
@@ -167,6 +296,18 @@ impl Level {
                     }
                 }
 
+                if waypoints != current_waypoints {
+                    let waypoints_string: Vec<_> =
+                        waypoints.iter().map(ToString::to_string).collect();
+                    content.push_str(&format!("Waypoints={}\n", waypoints_string.join(";")));
+                    current_waypoints = waypoints;
+                }
+
+                if channel != current_channel {
+                    content.push_str(&format!("Channel={}\n", channel.0));
+                    current_channel = channel;
+                }
+
                 content.push_str(&format!("Position={position}\n"));
             }
         }
@@ -174,3 +315,19 @@ impl Level {
         content
     }
 }
+
+fn parse_positions(value: &str) -> Vec<Position> {
+    value
+        .split(';')
+        .filter_map(|location| match location.split_once(',') {
+            Some((x, y)) => match (x.parse(), y.parse()) {
+                (Ok(x), Ok(y)) => Some(Position { x, y }),
+                _ => {
+                    println!("Invalid location ({x},{y})");
+                    None
+                }
+            },
+            _ => None,
+        })
+        .collect()
+}
@@ -0,0 +1,161 @@
+use std::{collections::BTreeMap, fs};
+
+use bevy::prelude::*;
+
+use crate::{
+    editor::{LevelValidation, ResizeDialog, ResizeSettings},
+    game_object::TransformationRules,
+    level::{Dimensions, Level, WeightOverrides, WinCondition},
+    save_current_level,
+    utils::get_level_filename,
+    LevelObject, Levels,
+};
+
+#[derive(Event)]
+pub enum LevelEvent {
+    /// Inserts an empty level after the current level, and opens the resize
+    /// dialog to choose its dimensions.
+    New,
+
+    /// Inserts a copy of the level on the board after the current level.
+    Duplicate,
+
+    /// Deletes the current level, unless it is the only one.
+    Delete,
+
+    /// Swaps the current level with the one before it.
+    MoveBackward,
+
+    /// Swaps the current level with the one after it.
+    MoveForward,
+}
+
+/// Manages the levels in the pack. Level files are renamed along with the
+/// levels, so their numbers keep matching the order of the pack.
+///
+/// Unless it gets deleted, the level on the board is saved first, so no
+/// changes are lost when the board is reloaded afterwards. If the level cannot
+/// be saved, nothing happens.
+#[allow(clippy::too_many_arguments)]
+pub fn on_level_event(
+    mut events: EventReader<LevelEvent>,
+    mut levels: ResMut<Levels>,
+    mut validation: ResMut<LevelValidation>,
+    mut resize_dialog: ResMut<ResizeDialog>,
+    dimensions: Res<Dimensions>,
+    objects_query: Query<LevelObject>,
+    transformation_rules: Res<TransformationRules>,
+    weight_overrides: Res<WeightOverrides>,
+    win_condition: Res<WinCondition>,
+) {
+    let Some(event) = events.read().last() else {
+        return;
+    };
+
+    if !matches!(event, LevelEvent::Delete)
+        && !save_current_level(
+            &mut levels,
+            &mut validation,
+            &dimensions,
+            &objects_query,
+            &transformation_rules,
+            &weight_overrides,
+            &win_condition,
+        )
+    {
+        return;
+    }
+
+    let current_level = levels.current_level;
+    let num_levels = levels.levels.len();
+
+    match event {
+        LevelEvent::New => {
+            let level = Level {
+                dimensions: *dimensions,
+                objects: BTreeMap::new(),
+                transformations: BTreeMap::new(),
+                weights: BTreeMap::new(),
+                win_condition: WinCondition::default(),
+            };
+            insert_level(&mut levels, current_level + 1, level.save());
+            resize_dialog.0 = Some(ResizeSettings::new(*dimensions));
+        }
+        LevelEvent::Duplicate => {
+            let content = levels.levels[current_level].to_string();
+            insert_level(&mut levels, current_level + 1, content);
+        }
+        LevelEvent::Delete => {
+            if num_levels == 1 {
+                return;
+            }
+
+            if cfg!(unix) {
+                if let Err(error) = fs::remove_file(get_level_filename(current_level + 1)) {
+                    println!("Could not delete level: {error}");
+                }
+                for index in current_level + 1..num_levels {
+                    rename_level_file(index + 1, index);
+                }
+            }
+
+            levels.levels.remove(current_level);
+            levels.current_level = current_level.min(num_levels - 2);
+        }
+        LevelEvent::MoveBackward => {
+            if current_level > 0 {
+                swap_levels(&mut levels, current_level - 1, current_level);
+                levels.current_level = current_level - 1;
+            }
+        }
+        LevelEvent::MoveForward => {
+            if current_level + 1 < num_levels {
+                swap_levels(&mut levels, current_level, current_level + 1);
+                levels.current_level = current_level + 1;
+            }
+        }
+    }
+}
+
+fn insert_level(levels: &mut Levels, index: usize, content: String) {
+    if cfg!(unix) {
+        for existing_index in (index..levels.levels.len()).rev() {
+            rename_level_file(existing_index + 1, existing_index + 2);
+        }
+        if let Err(error) = fs::write(get_level_filename(index + 1), &content) {
+            println!("Could not save level: {error}");
+        }
+    }
+
+    levels.levels.insert(index, content.into());
+    levels.current_level = index;
+}
+
+fn swap_levels(levels: &mut Levels, first_index: usize, second_index: usize) {
+    if cfg!(unix) {
+        let first_filename = get_level_filename(first_index + 1);
+        let second_filename = get_level_filename(second_index + 1);
+        let temporary_filename = format!("{first_filename}.tmp");
+        for (from, to) in [
+            (&first_filename, &temporary_filename),
+            (&second_filename, &first_filename),
+            (&temporary_filename, &second_filename),
+        ] {
+            if let Err(error) = fs::rename(from, to) {
+                println!("Could not rename level: {error}");
+            }
+        }
+    }
+
+    levels.levels.swap(first_index, second_index);
+}
+
+/// Renames the file of the level with the given number to the other number.
+fn rename_level_file(from_number: usize, to_number: usize) {
+    if let Err(error) = fs::rename(
+        get_level_filename(from_number),
+        get_level_filename(to_number),
+    ) {
+        println!("Could not rename level: {error}");
+    }
+}
@@ -1,14 +1,18 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 mod background;
+mod checkpoint;
 mod constants;
 mod editor;
 mod errors;
 mod fonts;
 mod game_object;
 mod gameover;
+mod hud;
 mod level;
+mod level_management;
 mod menu;
+mod playtest;
 mod timers;
 mod utils;
 
@@ -22,14 +26,28 @@ use bevy::{
     window::{WindowMode, WindowResized, WindowResolution},
     winit::WinitWindows,
 };
+use checkpoint::{
+    check_for_checkpoints, respawn_at_checkpoint, restore_object_states, CheckpointEvent,
+    CheckpointSnapshot,
+};
 use constants::*;
-use editor::{spawn_selected_object, Editor, EditorBundle, EditorPlugin, SelectedObjectType};
+use editor::{
+    spawn_selected_object, to_editor_object, Editor, EditorBundle, EditorHistory, EditorPlugin,
+    LevelIssue, LevelValidation, SelectedObjectType, SelectedTool, Selection, VisibleLayers,
+};
 use fonts::Fonts;
 use game_object::{Direction, *};
-use gameover::{check_for_game_over, setup_gameover};
-use level::{Dimensions, InitialPositionAndDirection, Level, LEVELS};
+use gameover::{check_for_game_over, setup_gameover, GameOverState};
+use hud::{setup_hud, update_hud, CollectedItems};
+use level::{
+    Dimensions, InitialPositionAndDirection, Level, WeightOverrides, WinCondition, LEVELS,
+};
+use level_management::{on_level_event, LevelEvent};
 use menu::{on_menu_interaction_input, on_menu_keyboard_input, render_menu, setup_menu, MenuState};
-use timers::{AnimationTimer, MovementTimer, TemporaryTimer, TransporterTimer};
+use playtest::{restore_playtest, start_playtest, PlaytestEvent, PlaytestSnapshot};
+use timers::{
+    AnimationTimer, CurrentTimer, ExplosionTimer, MovementTimer, TemporaryTimer, TransporterTimer,
+};
 use utils::get_level_filename;
 use winit::window::Icon;
 
@@ -50,7 +68,28 @@ impl Default for Levels {
 
 #[derive(Default, Resource)]
 struct PressedTriggers {
-    num_pressed_triggers: usize,
+    /// Number of pressed triggers on each channel that has any.
+    num_pressed_triggers: BTreeMap<Channel, usize>,
+
+    /// Number of pressed triggers on each channel before they last changed.
+    num_previously_pressed_triggers: BTreeMap<Channel, usize>,
+}
+
+impl PressedTriggers {
+    /// Returns whether more triggers got pressed on any channel when they
+    /// last changed.
+    fn is_triggered(&self) -> bool {
+        self.num_pressed_triggers
+            .iter()
+            .any(|(channel, num_pressed)| {
+                *num_pressed
+                    > self
+                        .num_previously_pressed_triggers
+                        .get(channel)
+                        .copied()
+                        .unwrap_or_default()
+            })
+    }
 }
 
 #[derive(Resource)]
@@ -66,11 +105,11 @@ impl Default for Zoom {
 
 #[derive(Event)]
 enum GameEvent {
-    ChangeWidth(i16),
-    ChangeHeight(i16),
     ChangeZoom(f32),
     LoadRelativeLevel(isize),
     MovePlayer(i16, i16),
+    RespawnAtCheckpoint,
+    SelectNextPlayer,
     ToggleEditor,
     Exit,
 }
@@ -80,6 +119,12 @@ enum EditorEvent {
     Toggle,
 }
 
+#[derive(Event)]
+enum PlayerEvent {
+    Moved(i16, i16),
+    SelectNext,
+}
+
 #[derive(Event)]
 enum SaveLevelEvent {
     Save,
@@ -106,18 +151,31 @@ fn main() {
         ))
         .init_resource::<AnimationTimer>()
         .init_resource::<BackgroundAsset>()
+        .init_resource::<CheckpointSnapshot>()
+        .init_resource::<CollectedItems>()
+        .init_resource::<CurrentTimer>()
         .init_resource::<Dimensions>()
+        .init_resource::<ExplosionTimer>()
         .init_resource::<Fonts>()
         .init_resource::<GameObjectAssets>()
+        .init_resource::<GameOverState>()
         .init_resource::<Levels>()
         .init_resource::<MenuState>()
         .init_resource::<MovementTimer>()
+        .init_resource::<PlaytestSnapshot>()
         .init_resource::<PressedTriggers>()
         .init_resource::<TemporaryTimer>()
+        .init_resource::<TransformationRules>()
         .init_resource::<TransporterTimer>()
+        .init_resource::<WeightOverrides>()
+        .init_resource::<WinCondition>()
         .init_resource::<Zoom>()
+        .add_event::<CheckpointEvent>()
         .add_event::<EditorEvent>()
         .add_event::<GameEvent>()
+        .add_event::<LevelEvent>()
+        .add_event::<PlayerEvent>()
+        .add_event::<PlaytestEvent>()
         .add_event::<SaveLevelEvent>()
         .add_event::<TransformEvent>()
         .add_systems(Startup, (set_window_icon, setup, setup_background))
@@ -126,6 +184,7 @@ fn main() {
             (
                 on_keyboard_input,
                 on_menu_interaction_input,
+                on_level_event,
                 on_resize_system,
                 save_level,
             ),
@@ -134,27 +193,73 @@ fn main() {
             Update,
             (
                 animate_objects,
+                check_for_collectibles,
+                check_for_current,
                 check_for_deadly,
                 check_for_exit,
                 check_for_explosive,
-                check_for_liquid,
                 check_for_game_over,
-                check_for_transform_on_push,
+                check_for_locked_exits,
+                check_for_pit,
+                check_for_transform_on_leave,
                 check_for_transporter,
                 despawn_volatile_objects,
                 move_objects,
                 on_game_event,
                 render_menu,
+                update_hud,
             )
                 .after(on_keyboard_input),
         )
+        .add_systems(Update, apply_weight_overrides.before(check_for_triggers))
         .add_systems(
             Update,
             check_for_triggers
                 .after(on_keyboard_input)
-                .after(move_objects),
+                .after(move_objects)
+                .after(update_lasers),
+        )
+        .add_systems(Update, check_for_transformations.after(check_for_triggers))
+        .add_systems(
+            Update,
+            load_level
+                .after(on_game_event)
+                .after(on_level_event)
+                .after(save_level),
+        )
+        .add_systems(
+            Update,
+            (
+                check_for_checkpoints.after(carry_passengers),
+                respawn_at_checkpoint.after(on_game_event),
+                restore_object_states
+                    .after(respawn_at_checkpoint)
+                    .before(check_for_transformations),
+            ),
+        )
+        .add_systems(Update, move_mirroring_objects.after(on_game_event))
+        .add_systems(Update, roll_objects.after(move_objects))
+        .add_systems(
+            Update,
+            carry_passengers
+                .after(check_for_current)
+                .after(check_for_transporter)
+                .after(move_mirroring_objects)
+                .after(move_objects)
+                .after(on_game_event)
+                .after(roll_objects),
+        )
+        .add_systems(Update, check_for_liquid.after(carry_passengers))
+        .add_systems(
+            Update,
+            update_lasers
+                .after(check_for_explosive)
+                .after(check_for_liquid),
+        )
+        .add_systems(
+            Update,
+            update_active_player.after(on_game_event).after(load_level),
         )
-        .add_systems(Update, load_level.after(on_game_event).after(save_level))
         .add_systems(
             Update,
             (position_entities, update_entity_directions)
@@ -162,6 +267,8 @@ fn main() {
                 .after(check_for_explosive)
                 .after(check_for_liquid)
                 .after(move_objects)
+                .after(move_mirroring_objects)
+                .after(roll_objects)
                 .after(spawn_selected_object),
         )
         .add_systems(
@@ -170,11 +277,19 @@ fn main() {
                 .after(load_level)
                 .after(on_resize_system),
         )
+        .add_systems(
+            Update,
+            (
+                start_playtest.before(toggle_editor),
+                restore_playtest.after(toggle_editor),
+            ),
+        )
         .add_systems(
             Update,
             update_background_transform
                 .after(toggle_editor)
-                .after(resize_background),
+                .after(resize_background)
+                .after(update_active_player),
         )
         .run();
 }
@@ -225,14 +340,15 @@ fn setup(
 
     setup_menu(&mut commands, &fonts);
     setup_gameover(&mut commands, &fonts);
+    setup_hud(&mut commands, &fonts);
 
     events.send(GameEvent::LoadRelativeLevel(0));
 }
 
 fn on_keyboard_input(
     mut events: EventWriter<GameEvent>,
-    player_query: Query<Entity, With<Player>>,
     menu_state: ResMut<MenuState>,
+    game_over_state: Res<GameOverState>,
     keys: Res<ButtonInput<KeyCode>>,
 ) {
     if menu_state.is_open {
@@ -240,6 +356,11 @@ fn on_keyboard_input(
         return;
     }
 
+    // Shortcuts with Ctrl are handled by the editor.
+    if keys.any_pressed([KeyCode::ControlLeft, KeyCode::ControlRight]) {
+        return;
+    }
+
     for key in keys.get_just_pressed() {
         use KeyCode::*;
         match key {
@@ -247,9 +368,9 @@ fn on_keyboard_input(
             ArrowRight => events.send(GameEvent::MovePlayer(1, 0)),
             ArrowDown => events.send(GameEvent::MovePlayer(0, 1)),
             ArrowLeft => events.send(GameEvent::MovePlayer(-1, 0)),
-            Enter if player_query.get_single().is_err() => {
-                events.send(GameEvent::LoadRelativeLevel(0))
-            }
+            Enter if game_over_state.is_game_over => events.send(GameEvent::LoadRelativeLevel(0)),
+            KeyC if game_over_state.is_game_over => events.send(GameEvent::RespawnAtCheckpoint),
+            Tab => events.send(GameEvent::SelectNextPlayer),
             Equal => events.send(GameEvent::ChangeZoom(1.25)),
             Minus => events.send(GameEvent::ChangeZoom(0.8)),
             BracketRight => events.send(GameEvent::LoadRelativeLevel(1)),
@@ -288,12 +409,14 @@ fn update_entity_directions(mut query: Query<(&Direction, &mut TextureAtlas), Ch
 #[allow(clippy::too_many_arguments)]
 fn on_game_event(
     mut app_exit_events: EventWriter<AppExit>,
-    mut collision_objects_query: Query<CollisionObject, Without<Player>>,
-    mut dimensions: ResMut<Dimensions>,
+    mut checkpoint_events: EventWriter<CheckpointEvent>,
+    mut collision_objects_query: Query<(CollisionObject, Has<Player>), Without<ActivePlayer>>,
+    dimensions: Res<Dimensions>,
     mut editor_events: EventWriter<EditorEvent>,
     mut level_events: EventReader<GameEvent>,
     mut levels: ResMut<Levels>,
-    mut player_query: Query<(&mut Position, Option<&Weight>), With<Player>>,
+    mut player_events: EventWriter<PlayerEvent>,
+    mut player_query: Query<(&mut Position, Option<&Weight>), With<ActivePlayer>>,
     mut transform_events: EventWriter<TransformEvent>,
     mut menu_state: ResMut<MenuState>,
     mut zoom: ResMut<Zoom>,
@@ -301,16 +424,6 @@ fn on_game_event(
 ) {
     for event in level_events.read() {
         match event {
-            GameEvent::ChangeHeight(delta) => {
-                if dimensions.height + delta > 0 {
-                    dimensions.height += delta;
-                }
-            }
-            GameEvent::ChangeWidth(delta) => {
-                if dimensions.width + delta > 0 {
-                    dimensions.width += delta;
-                }
-            }
             GameEvent::ChangeZoom(factor) => {
                 zoom.factor *= factor;
                 transform_events.send(TransformEvent::Update);
@@ -322,16 +435,38 @@ fn on_game_event(
             }
             GameEvent::MovePlayer(dx, dy) => {
                 if let Ok((mut position, weight)) = player_query.get_single_mut() {
-                    move_object(
+                    // Players cannot walk onto one another.
+                    let (x, y) = (position.x + dx, position.y + dy);
+                    if collision_objects_query
+                        .iter()
+                        .any(|((position, ..), is_player)| {
+                            is_player && position.x == x && position.y == y
+                        })
+                    {
+                        continue;
+                    }
+
+                    if move_object(
                         &mut position,
                         (*dx, *dy),
                         &dimensions,
-                        collision_objects_query.iter_mut(),
+                        collision_objects_query
+                            .iter_mut()
+                            .map(|(collision_object, _)| collision_object),
                         weight.copied().unwrap_or_default(),
-                    );
+                        weight.copied().unwrap_or_default(),
+                    ) {
+                        player_events.send(PlayerEvent::Moved(*dx, *dy));
+                    }
                     transform_events.send(TransformEvent::Update);
                 }
             }
+            GameEvent::RespawnAtCheckpoint => {
+                checkpoint_events.send(CheckpointEvent::Respawn);
+            }
+            GameEvent::SelectNextPlayer => {
+                player_events.send(PlayerEvent::SelectNext);
+            }
             GameEvent::ToggleEditor => {
                 editor_events.send(EditorEvent::Toggle);
             }
@@ -359,12 +494,20 @@ fn on_resize_system(
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn load_level(
     mut commands: Commands,
     mut background_query: Query<Entity, With<Background>>,
+    mut checkpoint_snapshot: ResMut<CheckpointSnapshot>,
+    mut collected_items: ResMut<CollectedItems>,
     mut dimensions: ResMut<Dimensions>,
+    mut editor_history: ResMut<EditorHistory>,
     mut levels: ResMut<Levels>,
     mut pressed_triggers: ResMut<PressedTriggers>,
+    mut selection: ResMut<Selection>,
+    mut transformation_rules: ResMut<TransformationRules>,
+    mut weight_overrides: ResMut<WeightOverrides>,
+    mut win_condition: ResMut<WinCondition>,
     assets: Res<GameObjectAssets>,
 ) {
     if !levels.is_changed() {
@@ -391,50 +534,112 @@ fn load_level(
         spawn_level_objects(cb, level.objects, &assets);
     });
 
-    pressed_triggers.num_pressed_triggers = 0;
+    checkpoint_snapshot.clear();
+    editor_history.clear();
+    *selection = Selection::default();
+    *collected_items = CollectedItems::default();
+    *pressed_triggers = PressedTriggers::default();
 
     *dimensions = level.dimensions;
+    transformation_rules.overrides = level.transformations;
+    weight_overrides.0 = level.weights;
+    *win_condition = level.win_condition;
+}
+
+type LevelObject<'a> = (
+    &'a ObjectType,
+    &'a Position,
+    Option<&'a Direction>,
+    Option<&'a Waypoints>,
+    Option<&'a Channel>,
+);
+
+/// Collects the objects within the level's dimensions, in the form they are
+/// saved in.
+fn collect_level_objects<'a>(
+    objects: impl Iterator<Item = LevelObject<'a>>,
+    dimensions: &Dimensions,
+) -> BTreeMap<ObjectType, Vec<InitialPositionAndDirection>> {
+    let mut level_objects = BTreeMap::new();
+    for object in objects {
+        let (object_type, object) = to_editor_object(object);
+        if object.position.x > 0
+            && object.position.x <= dimensions.width
+            && object.position.y > 0
+            && object.position.y <= dimensions.height
+        {
+            let positions = level_objects.entry(object_type).or_insert(Vec::new());
+            positions.push(object);
+        }
+    }
+    level_objects
 }
 
+#[allow(clippy::too_many_arguments)]
 fn save_level(
     mut events: EventReader<SaveLevelEvent>,
     mut levels: ResMut<Levels>,
+    mut validation: ResMut<LevelValidation>,
     dimensions: Res<Dimensions>,
-    objects_query: Query<(&ObjectType, &Position, Option<&Direction>)>,
+    objects_query: Query<LevelObject>,
+    transformation_rules: Res<TransformationRules>,
+    weight_overrides: Res<WeightOverrides>,
+    win_condition: Res<WinCondition>,
 ) {
     let Some(_event) = events.read().last() else {
         return;
     };
 
-    let mut objects = BTreeMap::new();
-    for (object_type, position, direction) in &objects_query {
-        if position.x > 0
-            && position.x <= dimensions.width
-            && position.y > 0
-            && position.y <= dimensions.height
-        {
-            let positions = objects.entry(*object_type).or_insert(Vec::new());
-            positions.push(InitialPositionAndDirection {
-                position: *position,
-                direction: direction.copied(),
-            });
-        }
-    }
+    save_current_level(
+        &mut levels,
+        &mut validation,
+        &dimensions,
+        &objects_query,
+        &transformation_rules,
+        &weight_overrides,
+        &win_condition,
+    );
+}
 
-    if !objects
-        .get(&ObjectType::Player)
-        .is_some_and(|player_locations| player_locations.len() == 1)
-    {
-        return; // Only save levels with exactly one player.
-    }
+/// Saves the level on the board over the current level. Returns whether the
+/// level is saved, which it is not if it has changed but has no player.
+fn save_current_level(
+    levels: &mut Levels,
+    validation: &mut LevelValidation,
+    dimensions: &Dimensions,
+    objects_query: &Query<LevelObject>,
+    transformation_rules: &TransformationRules,
+    weight_overrides: &WeightOverrides,
+    win_condition: &WinCondition,
+) -> bool {
+    let objects = collect_level_objects(objects_query.iter(), dimensions);
+    let has_player = objects.contains_key(&ObjectType::Player);
 
     let level = Level {
         dimensions: *dimensions,
         objects,
+        transformations: transformation_rules.overrides.clone(),
+        weights: weight_overrides.0.clone(),
+        win_condition: *win_condition,
     };
     let content = level.save();
     let current_level = levels.current_level;
 
+    // Unchanged levels need no saving, such as levels that were just created.
+    if levels.levels[current_level] == content {
+        validation.save_refused = false;
+        return true;
+    }
+
+    if !has_player {
+        // Only save levels with at least one player.
+        println!("Could not save level: {}", LevelIssue::NoPlayer);
+        validation.save_refused = true;
+        return false;
+    }
+
+    validation.save_refused = false;
+
     if cfg!(unix) {
         if let Err(error) = fs::write(get_level_filename(current_level + 1), &content) {
             println!("Could not save level: {error}");
@@ -442,35 +647,93 @@ fn save_level(
     }
 
     levels.levels[current_level] = content.into();
+    true
 }
 
 fn spawn_level_objects(
     commands: &mut ChildBuilder,
-    objects: BTreeMap<ObjectType, Vec<InitialPositionAndDirection>>,
+    objects: impl IntoIterator<Item = (ObjectType, Vec<InitialPositionAndDirection>)>,
     assets: &GameObjectAssets,
 ) {
     for (object_type, initial_positions) in objects {
         for InitialPositionAndDirection {
             position,
             direction,
+            waypoints,
+            channel,
         } in initial_positions
         {
-            spawn_object_of_type(
+            let mut object = spawn_object_of_type(
                 commands,
                 assets,
                 object_type,
                 position,
                 direction.unwrap_or_default(),
             );
+            if !waypoints.is_empty() {
+                object.insert(Waypoints::new(waypoints));
+            }
+            if channel != Channel::default() {
+                object.insert(channel);
+            }
         }
     }
 }
 
+fn update_active_player(
+    mut commands: Commands,
+    mut events: EventReader<PlayerEvent>,
+    mut player_query: Query<(Entity, &Position, Has<ActivePlayer>, &mut Sprite), With<Player>>,
+    mut transform_events: EventWriter<TransformEvent>,
+) {
+    let num_selections = events
+        .read()
+        .filter(|event| matches!(event, PlayerEvent::SelectNext))
+        .count();
+
+    let mut players: Vec<_> = player_query.iter_mut().collect();
+    if players.is_empty() {
+        return;
+    }
+
+    let active_index = players.iter().position(|(.., is_active, _)| *is_active);
+    if active_index.is_some() && num_selections == 0 {
+        return;
+    }
+
+    // Players are cycled through in reading order.
+    players.sort_unstable_by_key(|(_, position, ..)| (position.y, position.x));
+    let active_index = players.iter().position(|(.., is_active, _)| *is_active);
+    let new_active_index = match active_index {
+        Some(index) => (index + num_selections) % players.len(),
+        None => 0,
+    };
+    if active_index == Some(new_active_index) {
+        return;
+    }
+
+    for (index, (player, _, is_active, sprite)) in players.iter_mut().enumerate() {
+        if index == new_active_index {
+            commands.entity(*player).insert(ActivePlayer);
+            sprite.color = WHITE;
+        } else {
+            if *is_active {
+                commands.entity(*player).remove::<ActivePlayer>();
+            }
+            sprite.color = TRANSLUCENT_WHITE;
+        }
+    }
+
+    transform_events.send(TransformEvent::Update);
+}
+
 #[allow(clippy::too_many_arguments)]
 fn toggle_editor(
     mut commands: Commands,
     mut events: EventReader<EditorEvent>,
     mut transform_events: EventWriter<TransformEvent>,
+    mut current_timer: ResMut<CurrentTimer>,
+    mut explosion_timer: ResMut<ExplosionTimer>,
     mut movement_timer: ResMut<MovementTimer>,
     mut selected_object_type: ResMut<SelectedObjectType>,
     mut temporary_timer: ResMut<TemporaryTimer>,
@@ -479,6 +742,8 @@ fn toggle_editor(
     assets: Res<GameObjectAssets>,
     dimensions: Res<Dimensions>,
     fonts: Res<Fonts>,
+    selected_tool: Res<SelectedTool>,
+    visible_layers: Res<VisibleLayers>,
 ) {
     let Some(_event) = events.read().last() else {
         return;
@@ -488,14 +753,25 @@ fn toggle_editor(
         commands.entity(editor).despawn_recursive();
         **selected_object_type = None;
 
+        current_timer.unpause();
+        explosion_timer.unpause();
         movement_timer.unpause();
         temporary_timer.unpause();
         transporter_timer.unpause();
     } else {
-        commands
-            .spawn(EditorBundle::new())
-            .with_children(|cb| EditorBundle::populate(cb, &assets, &dimensions, &fonts));
+        commands.spawn(EditorBundle::new()).with_children(|cb| {
+            EditorBundle::populate(
+                cb,
+                &assets,
+                &dimensions,
+                **selected_tool,
+                &visible_layers,
+                &fonts,
+            )
+        });
 
+        current_timer.pause();
+        explosion_timer.pause();
         movement_timer.pause();
         temporary_timer.pause();
         transporter_timer.pause();
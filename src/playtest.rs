@@ -0,0 +1,131 @@
+use bevy::prelude::*;
+
+use crate::{
+    background::Background,
+    checkpoint::CheckpointSnapshot,
+    collect_level_objects,
+    editor::Editor,
+    game_object::{GameObjectAssets, Player, PlayerBundle, Position, TransformationRules},
+    hud::CollectedItems,
+    level::{Dimensions, Level, WeightOverrides, WinCondition},
+    spawn_level_objects, EditorEvent, LevelObject, Levels, PressedTriggers,
+};
+
+#[derive(Event)]
+pub enum PlaytestEvent {
+    /// Closes the editor to play the level, optionally with the player
+    /// starting from the given position.
+    Start(Option<Position>),
+}
+
+/// The level as it was in the editor when the playtest started. It gets
+/// restored when the editor is opened again.
+#[derive(Default, Resource)]
+pub struct PlaytestSnapshot {
+    level: Option<(usize, Level)>,
+}
+
+#[allow(clippy::too_many_arguments, clippy::type_complexity)]
+pub fn start_playtest(
+    mut commands: Commands,
+    mut events: EventReader<PlaytestEvent>,
+    mut editor_events: EventWriter<EditorEvent>,
+    mut queries: ParamSet<(Query<LevelObject>, Query<&mut Position, With<Player>>)>,
+    mut snapshot: ResMut<PlaytestSnapshot>,
+    background_query: Query<Entity, With<Background>>,
+    editor_query: Query<Entity, With<Editor>>,
+    levels: Res<Levels>,
+    dimensions: Res<Dimensions>,
+    transformation_rules: Res<TransformationRules>,
+    weight_overrides: Res<WeightOverrides>,
+    win_condition: Res<WinCondition>,
+    assets: Res<GameObjectAssets>,
+) {
+    let Some(PlaytestEvent::Start(start_position)) = events.read().last() else {
+        return;
+    };
+
+    if editor_query.get_single().is_err() {
+        return;
+    }
+
+    let level = Level {
+        dimensions: *dimensions,
+        objects: collect_level_objects(queries.p0().iter(), &dimensions),
+        transformations: transformation_rules.overrides.clone(),
+        weights: weight_overrides.0.clone(),
+        win_condition: *win_condition,
+    };
+    snapshot.level = Some((levels.current_level, level));
+
+    editor_events.send(EditorEvent::Toggle);
+
+    let Some(start_position) = *start_position else {
+        return;
+    };
+
+    // The player that would be selected first is moved to the start position.
+    let mut player_query = queries.p1();
+    let mut players: Vec<_> = player_query.iter_mut().collect();
+    players.sort_unstable_by_key(|position| (position.y, position.x));
+    if let Some(position) = players.first_mut() {
+        **position = start_position;
+    } else {
+        let background = background_query
+            .get_single()
+            .expect("there should be only one background");
+
+        commands.entity(background).with_children(|cb| {
+            cb.spawn(PlayerBundle::spawn(&assets, start_position));
+        });
+    }
+}
+
+#[allow(clippy::too_many_arguments)]
+pub fn restore_playtest(
+    mut commands: Commands,
+    mut checkpoint_snapshot: ResMut<CheckpointSnapshot>,
+    mut collected_items: ResMut<CollectedItems>,
+    mut dimensions: ResMut<Dimensions>,
+    mut pressed_triggers: ResMut<PressedTriggers>,
+    mut snapshot: ResMut<PlaytestSnapshot>,
+    mut transformation_rules: ResMut<TransformationRules>,
+    mut weight_overrides: ResMut<WeightOverrides>,
+    mut win_condition: ResMut<WinCondition>,
+    background_query: Query<Entity, With<Background>>,
+    opened_editor_query: Query<(), Added<Editor>>,
+    levels: Res<Levels>,
+    assets: Res<GameObjectAssets>,
+) {
+    if opened_editor_query.is_empty() {
+        return;
+    }
+
+    let Some((level_index, level)) = snapshot.level.take() else {
+        return;
+    };
+
+    // Playtests that ended up in another level are not restored.
+    if level_index != levels.current_level {
+        return;
+    }
+
+    let background_entity = background_query
+        .get_single()
+        .expect("there should be only one background");
+
+    let mut background = commands.entity(background_entity);
+    background.despawn_descendants();
+    background.with_children(|cb| {
+        spawn_level_objects(cb, level.objects, &assets);
+    });
+
+    checkpoint_snapshot.clear();
+    *collected_items = CollectedItems::default();
+    *pressed_triggers = PressedTriggers::default();
+
+    *dimensions = level.dimensions;
+    transformation_rules.overrides = level.transformations;
+    weight_overrides.0 = level.weights;
+    *win_condition = level.win_condition;
+}
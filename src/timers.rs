@@ -25,6 +25,52 @@ impl DerefMut for AnimationTimer {
     }
 }
 
+#[derive(Resource)]
+pub struct CurrentTimer(Timer);
+
+impl Default for CurrentTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.4, TimerMode::Repeating))
+    }
+}
+
+impl Deref for CurrentTimer {
+    type Target = Timer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for CurrentTimer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+#[derive(Resource)]
+pub struct ExplosionTimer(Timer);
+
+impl Default for ExplosionTimer {
+    fn default() -> Self {
+        Self(Timer::from_seconds(0.2, TimerMode::Repeating))
+    }
+}
+
+impl Deref for ExplosionTimer {
+    type Target = Timer;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl DerefMut for ExplosionTimer {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
 #[derive(Resource)]
 pub struct MovementTimer(Timer);
 